@@ -72,11 +72,15 @@
 /// Implements a singly-linked queue that can take any type of data
 mod singly_linked_queue {
 
+    use std::fmt::{self, Debug};
+    use std::iter::FusedIterator;
     use std::ptr;
 
     pub struct Queue<T> {
         head: PointerToQueueNode<T>,
         tail: *mut QueueNode<T>,
+        len: usize,
+        capacity: Option<usize>,
     }
 
     type PointerToQueueNode<T> = *mut QueueNode<T>;
@@ -90,15 +94,41 @@ mod singly_linked_queue {
 
     pub struct Iter<'a, T> {
         next: Option<&'a QueueNode<T>>,
+        len: usize,
     }
 
     pub struct IterMut<'a, T> {
         next: Option<&'a mut QueueNode<T>>,
+        len: usize,
+    }
+
+    impl<T> Default for Queue<T> {
+        fn default() -> Self {
+            Self::new()
+        }
     }
 
     impl<T> Queue<T> {
         pub fn new() -> Self {
-            Queue { head: ptr::null_mut(), tail: ptr::null_mut() }
+            Queue { head: ptr::null_mut(), tail: ptr::null_mut(), len: 0, capacity: None }
+        }
+
+        /// Creates an empty queue with an advisory capacity of `cap` elements
+        /// - The capacity is only checked by [`Queue::try_push`]; `push`, `push_front`, `extend`, `append` and `From<Vec<T>>` all ignore it
+        pub fn with_capacity(cap: usize) -> Self {
+            Queue { capacity: Some(cap), ..Queue::new() }
+        }
+
+        /// Pushes `elem` unless the queue already holds its capacity, handing it back in `Err` if so
+        /// - This is the only method that checks the capacity, which is advisory everywhere else
+        pub fn try_push(&mut self, elem: T) -> Result<(), T> {
+            match self.capacity {
+                Some(cap) if self.len >= cap => Err(elem),
+                _ => {
+                    self.push(elem);
+                    Ok(())
+                }
+            }
         }
         pub fn push(&mut self, elem: T) {
             unsafe {
@@ -114,8 +144,24 @@ mod singly_linked_queue {
                 }
 
                 self.tail = new_tail;
+                self.len += 1;
+            }
+        }
+        // The inverse of `push`: puts the element at the front, to be dequeued next
+        pub fn push_front(&mut self, elem: T) {
+            let new_head = Box::into_raw(Box::new(QueueNode {
+                elem,
+                next: self.head,
+            }));
+
+            if self.tail.is_null() {
+                self.tail = new_head;
             }
+
+            self.head = new_head;
+            self.len += 1;
         }
+
         pub fn pop(&mut self) -> Option<T> {
             unsafe {
                 if self.head.is_null() {
@@ -128,11 +174,16 @@ mod singly_linked_queue {
                         self.tail = ptr::null_mut();
                     }
 
+                    self.len -= 1;
                     Some(head.elem)
                 }
             }
         }
 
+        pub fn extend_from_slice(&mut self, items: &[T]) where T: Clone {
+            self.extend(items.iter().cloned());
+        }
+
         pub fn peek(&self) -> Option<&T> {
             unsafe {
                 self.head.as_ref().map(|node| &node.elem)
@@ -145,26 +196,264 @@ mod singly_linked_queue {
             }
         }
 
+        pub fn peek_nth(&self, n: usize) -> Option<&T> {
+            let mut cur = self.head;
+            unsafe {
+                for _ in 0..n {
+                    if cur.is_null() {
+                        return None;
+                    }
+                    cur = (*cur).next;
+                }
+                cur.as_ref().map(|node| &node.elem)
+            }
+        }
+
+        pub fn contains(&self, target: &T) -> bool where T: PartialEq {
+            self.position(target).is_some()
+        }
+
+        // Distance from the head of the first element equal to `target`
+        pub fn position(&self, target: &T) -> Option<usize> where T: PartialEq {
+            self.iter().position(|elem| elem == target)
+        }
+
+        pub fn peek_back(&self) -> Option<&T> {
+            unsafe {
+                self.tail.as_ref().map(|node| &node.elem)
+            }
+        }
+
+        pub fn peek_back_mut(&mut self) -> Option<&mut T> {
+            unsafe {
+                self.tail.as_mut().map(|node| &mut node.elem)
+            }
+        }
+
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.head.is_null()
+        }
+
+        // Splices all of `other`'s nodes onto our tail in O(1), leaving `other` empty
+        pub fn append(&mut self, other: &mut Queue<T>) {
+            if other.head.is_null() {
+                return;
+            }
+
+            unsafe {
+                if !self.tail.is_null() {
+                    (*self.tail).next = other.head;
+                } else {
+                    self.head = other.head;
+                }
+            }
+
+            self.tail = other.tail;
+            self.len += other.len;
+
+            other.head = ptr::null_mut();
+            other.tail = ptr::null_mut();
+            other.len = 0;
+        }
+
+        // Unlinks and frees every node whose element matches `pred`, returning the removed elements in FIFO order
+        pub fn drain_filter<F: FnMut(&T) -> bool>(&mut self, pred: F) -> Vec<T> {
+            let mut removed = Vec::new();
+            self.remove_where(pred, |elem| removed.push(elem));
+            removed
+        }
+
+        // Keeps only the elements matching `pred`, freeing the rest in place
+        pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut pred: F) {
+            self.remove_where(|elem| !pred(elem), drop);
+        }
+
+        // Shared by `drain_filter` and `retain`: unlinks every node matching `pred` and hands its element to `removed`
+        fn remove_where<F, G>(&mut self, mut pred: F, mut removed: G)
+        where
+            F: FnMut(&T) -> bool,
+            G: FnMut(T),
+        {
+            let mut prev: PointerToQueueNode<T> = ptr::null_mut();
+            let mut cur = self.head;
+
+            unsafe {
+                while !cur.is_null() {
+                    let next = (*cur).next;
+                    if pred(&(*cur).elem) {
+                        // Point whatever led to `cur` (the previous node or `head`) past it
+                        if prev.is_null() {
+                            self.head = next;
+                        } else {
+                            (*prev).next = next;
+                        }
+                        if cur == self.tail {
+                            self.tail = prev;
+                        }
+                        self.len -= 1;
+                        removed(Box::from_raw(cur).elem);
+                    } else {
+                        prev = cur;
+                    }
+                    cur = next;
+                }
+            }
+        }
+
+        pub fn clear(&mut self) {
+            // `pop` frees each node and nulls out `tail` once the queue is empty
+            while self.pop().is_some() { }
+        }
+
+        // Panics if `head`, `tail` and `len` disagree about the shape of the chain
+        #[cfg(test)]
+        pub fn assert_invariants(&self) {
+            if self.head.is_null() {
+                assert!(self.tail.is_null(), "empty queue has a dangling tail");
+                assert_eq!(self.len, 0, "empty queue has a non-zero len");
+                return;
+            }
+            assert!(!self.tail.is_null(), "non-empty queue has a null tail");
+
+            let mut count = 0;
+            let mut last = ptr::null_mut();
+            let mut cur = self.head;
+            unsafe {
+                while !cur.is_null() {
+                    count += 1;
+                    // A chain that loops back on itself would walk forever, so give up once we're past `len`
+                    assert!(count <= self.len, "walked more than len ({}) nodes from head", self.len);
+                    last = cur;
+                    cur = (*cur).next;
+                }
+            }
+            assert_eq!(count, self.len, "len doesn't match the number of nodes");
+            assert_eq!(last, self.tail, "walking from head doesn't end at tail");
+        }
+
+        // Floyd's tortoise and hare: `fast` moves two nodes for every one of `slow`,
+        // so it either runs off the end or, if the chain loops, laps `slow` and meets it
+        #[cfg(test)]
+        pub fn has_cycle(&self) -> bool {
+            let mut slow = self.head;
+            let mut fast = self.head;
+            unsafe {
+                while !fast.is_null() && !(*fast).next.is_null() {
+                    slow = (*slow).next;
+                    fast = (*(*fast).next).next;
+                    if slow == fast {
+                        return true;
+                    }
+                }
+            }
+            false
+        }
+
         pub fn into_iter(self) -> IntoIter<T> {
             IntoIter(self)
         }
 
         pub fn iter(&self) -> Iter<'_, T> {
             unsafe {
-                Iter { next: self.head.as_ref() }
+                Iter { next: self.head.as_ref(), len: self.len }
             }
         }
 
         pub fn iter_mut(&mut self) -> IterMut<'_, T> {
             unsafe {
-                IterMut { next: self.head.as_mut() }
+                IterMut { next: self.head.as_mut(), len: self.len }
+            }
+        }
+    }
+
+    impl<T: Clone> Clone for Queue<T> {
+        fn clone(&self) -> Self {
+            // Push fresh copies so the clone gets its own nodes and `tail`
+            let mut queue = Queue { capacity: self.capacity, ..Queue::new() };
+            for elem in self.iter() {
+                queue.push(elem.clone());
+            }
+            queue
+        }
+    }
+
+    impl<T> FromIterator<T> for Queue<T> {
+        fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+            let mut queue = Queue::new();
+            queue.extend(iter);
+            queue
+        }
+    }
+
+    impl<T> Extend<T> for Queue<T> {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+            for elem in iter {
+                self.push(elem);
             }
         }
     }
 
+    impl<T> From<Vec<T>> for Queue<T> {
+        // Pushed in vec order, so `v[0]` is dequeued first
+        fn from(v: Vec<T>) -> Self {
+            v.into_iter().collect()
+        }
+    }
+
+    impl<T> From<Queue<T>> for Vec<T> {
+        // Drained in FIFO order, so the head ends up at index 0
+        fn from(queue: Queue<T>) -> Self {
+            queue.into_iter().collect()
+        }
+    }
+
+    impl<T: PartialEq> PartialEq for Queue<T> {
+        // Only the elements are compared, not the capacity
+        fn eq(&self, other: &Self) -> bool {
+            self.len == other.len && self.iter().eq(other.iter())
+        }
+    }
+
+    impl<T: Debug> Debug for Queue<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_list().entries(self.iter()).finish()
+        }
+    }
+
     impl<T> Drop for Queue<T> {
         fn drop(&mut self) {
-            while let Some(_) = self.pop() { }
+            self.clear();
+        }
+    }
+
+    impl<T> IntoIterator for Queue<T> {
+        type Item = T;
+        type IntoIter = IntoIter<T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            IntoIter(self)
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a Queue<T> {
+        type Item = &'a T;
+        type IntoIter = Iter<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a mut Queue<T> {
+        type Item = &'a mut T;
+        type IntoIter = IterMut<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
         }
     }
 
@@ -173,8 +462,16 @@ mod singly_linked_queue {
         fn next(&mut self) -> Option<Self::Item> {
             self.0.pop()
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0.len, Some(self.0.len))
+        }
     }
 
+    impl<T> ExactSizeIterator for IntoIter<T> {}
+
+    impl<T> FusedIterator for IntoIter<T> {}
+
     impl<'a, T> Iterator for Iter<'a, T> {
         type Item = &'a T;
 
@@ -182,12 +479,21 @@ mod singly_linked_queue {
             unsafe {
                 self.next.map(|node| {
                     self.next = node.next.as_ref();
+                    self.len -= 1;
                     &node.elem
                 })
             }
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
     }
 
+    impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+    impl<T> FusedIterator for Iter<'_, T> {}
+
     impl<'a, T> Iterator for IterMut<'a, T> {
         type Item = &'a mut T;
 
@@ -195,12 +501,21 @@ mod singly_linked_queue {
             unsafe {
                 self.next.take().map(|node| {
                     self.next = node.next.as_mut();
+                    self.len -= 1;
                     &mut node.elem
                 })
             }
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
     }
 
+    impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+    impl<T> FusedIterator for IterMut<'_, T> {}
+
     #[cfg(test)]
     mod test {
         use crate::fifth::singly_linked_queue;
@@ -244,6 +559,314 @@ mod singly_linked_queue {
             assert_eq!(list.pop(), None);
         }
 
+        #[test]
+        fn len() {
+            let mut list = Queue::new();
+            assert!(list.is_empty());
+            assert_eq!(list.len(), 0);
+
+            list.push(1); list.push(2); list.push(3);
+            assert_eq!(list.len(), 3);
+            list.push_front(0);
+            assert_eq!(list.len(), 4);
+            list.append(&mut Queue::from(vec![4, 5]));
+            assert_eq!(list.len(), 6);
+            list.retain(|&x| x % 2 == 1);
+            assert_eq!(list.len(), 3);
+            assert_eq!(list.len(), list.iter().count());
+            assert!(!list.is_empty());
+
+            list.pop();
+            list.push(4);
+            list.pop();
+            assert_eq!(list.len(), 2);
+
+            list.pop(); list.pop();
+            assert!(list.is_empty());
+            assert_eq!(list.len(), 0);
+
+            list.push(5);
+            assert_eq!(list.len(), 1);
+            assert_eq!(list.pop(), Some(5));
+        }
+
+        #[test]
+        fn default() {
+            let mut list: Queue<i32> = Queue::default();
+            assert!(list.is_empty());
+            assert_eq!(list.pop(), None);
+
+            list.push(1); list.push(2);
+            assert_eq!(list.pop(), Some(1));
+            assert_eq!(list.pop(), Some(2));
+            assert_eq!(list.pop(), None);
+        }
+
+        #[test]
+        fn clone() {
+            let mut list = Queue::new();
+            list.push(1); list.push(2); list.push(3);
+
+            let mut cloned = list.clone();
+            assert_eq!(cloned.pop(), Some(1));
+            assert_eq!(cloned.pop(), Some(2));
+            assert_eq!(cloned.pop(), Some(3));
+            assert_eq!(cloned.pop(), None);
+
+            // The clone's tail is its own, so pushing to it is safe
+            cloned.push(4);
+            assert_eq!(cloned.pop(), Some(4));
+
+            assert_eq!(list.pop(), Some(1));
+            assert_eq!(list.pop(), Some(2));
+            assert_eq!(list.pop(), Some(3));
+            assert_eq!(list.pop(), None);
+        }
+
+        #[test]
+        fn debug() {
+            let mut list = Queue::new();
+            assert_eq!(format!("{:?}", list), "[]");
+
+            list.push(1); list.push(2); list.push(3);
+            assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+            assert_eq!(list.pop(), Some(1));
+        }
+
+        #[test]
+        fn clear() {
+            let mut list = Queue::new();
+            list.push(1); list.push(2); list.push(3);
+
+            list.clear();
+            assert!(list.is_empty());
+            assert_eq!(list.peek(), None);
+            assert_eq!(list.pop(), None);
+
+            list.push(4); list.push(5);
+            assert_eq!(list.pop(), Some(4));
+            assert_eq!(list.pop(), Some(5));
+            assert_eq!(list.pop(), None);
+        }
+
+        #[test]
+        fn peek_back() {
+            let mut list = Queue::new();
+            assert_eq!(list.peek_back(), None);
+            assert_eq!(list.peek_back_mut(), None);
+
+            list.push(1);
+            assert_eq!(list.peek_back(), Some(&1));
+            list.push(2); list.push(3);
+            assert_eq!(list.peek_back(), Some(&3));
+
+            if let Some(value) = list.peek_back_mut() { *value = 42 }
+            assert_eq!(list.peek_back(), Some(&42));
+            assert_eq!(list.peek(), Some(&1));
+
+            list.pop(); list.pop();
+            assert_eq!(list.peek_back(), list.peek());
+            assert_eq!(list.pop(), Some(42));
+            assert_eq!(list.peek_back(), None);
+        }
+
+        #[test]
+        fn peek_nth() {
+            let mut list = Queue::new();
+            assert_eq!(list.peek_nth(0), None);
+
+            list.push(1); list.push(2); list.push(3);
+            assert_eq!(list.peek_nth(0), list.peek());
+            assert_eq!(list.peek_nth(1), Some(&2));
+            assert_eq!(list.peek_nth(2), list.peek_back());
+            assert_eq!(list.peek_nth(3), None);
+            assert_eq!(list.peek_nth(100), None);
+        }
+
+        #[test]
+        fn with_capacity() {
+            let mut list = Queue::with_capacity(2);
+            assert_eq!(list.try_push(1), Ok(()));
+            assert_eq!(list.try_push(2), Ok(()));
+            assert_eq!(list.try_push(3), Err(3));
+            assert_eq!(list.len(), 2);
+
+            assert_eq!(list.pop(), Some(1));
+            assert_eq!(list.try_push(4), Ok(()));
+            assert_eq!(list.try_push(5), Err(5));
+
+            let mut cloned = list.clone();
+            assert_eq!(cloned.try_push(6), Err(6));
+
+            assert_eq!(list.pop(), Some(2));
+            assert_eq!(list.pop(), Some(4));
+            assert_eq!(list.pop(), None);
+        }
+
+        #[test]
+        fn push_ignores_capacity() {
+            let mut list = Queue::with_capacity(1);
+            list.push(1);
+            list.push(2);
+            assert_eq!(list.len(), 2);
+            assert_eq!(list.try_push(3), Err(3));
+
+            assert_eq!(list.pop(), Some(1));
+            assert_eq!(list.pop(), Some(2));
+            assert_eq!(list.pop(), None);
+        }
+
+        #[test]
+        fn with_capacity_zero() {
+            let mut list = Queue::with_capacity(0);
+            assert_eq!(list.try_push(1), Err(1));
+            assert!(list.is_empty());
+        }
+
+        #[test]
+        fn try_push_unbounded() {
+            let mut list = Queue::new();
+            for i in 0..100 {
+                assert_eq!(list.try_push(i), Ok(()));
+            }
+            assert_eq!(list.len(), 100);
+            assert_eq!(list.pop(), Some(0));
+        }
+
+        #[test]
+        fn extend_from_slice() {
+            let mut list = Queue::new();
+            list.push(1);
+            list.extend_from_slice(&[2, 3, 4]);
+            assert_eq!(list.peek_back(), Some(&4));
+
+            // The tail must point at the end of the batch
+            list.push(5);
+            assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+        }
+
+        #[test]
+        fn from_iterator() {
+            let mut list: Queue<i32> = (0..4).collect();
+            assert_eq!(list.peek_back(), Some(&3));
+            list.push(4);
+            assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+        }
+
+        #[test]
+        fn extend() {
+            let mut list = Queue::new();
+            list.push(0);
+            list.extend(1..4);
+            assert_eq!(list.len(), 4);
+            assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        }
+
+        #[test]
+        fn contains() {
+            let mut list = Queue::new();
+            assert!(!list.contains(&1));
+
+            list.push(1); list.push(2); list.push(3);
+            assert!(list.contains(&1));
+            assert!(list.contains(&3));
+            assert!(!list.contains(&4));
+        }
+
+        #[test]
+        fn position() {
+            let mut list = Queue::new();
+            assert_eq!(list.position(&1), None);
+
+            list.push(1); list.push(2); list.push(3); list.push(2);
+            assert_eq!(list.position(&1), Some(0));
+            assert_eq!(list.position(&3), Some(2));
+            assert_eq!(list.position(&2), Some(1));
+            assert_eq!(list.position(&4), None);
+
+            list.pop();
+            assert_eq!(list.position(&2), Some(0));
+        }
+
+        #[test]
+        fn eq() {
+            let list: Queue<i32> = (1..=3).collect();
+            let mut other = Queue::with_capacity(3);
+            other.push(0); other.push(1); other.push(2);
+            other.pop();
+            other.push(3);
+            assert_eq!(list, other);
+            assert_eq!(Queue::<i32>::new(), Queue::new());
+        }
+
+        #[test]
+        fn ne() {
+            let list: Queue<i32> = (1..=3).collect();
+            assert_ne!(list, vec![1, 5, 3].into_iter().collect());
+            assert_ne!(list, (1..=2).collect());
+            assert_ne!(list, (1..=4).collect());
+            assert_ne!(list, Queue::new());
+            assert_eq!(list.len(), 3);
+        }
+
+        #[test]
+        fn push_front() {
+            let mut list = Queue::new();
+            list.push_front(2);
+            assert_eq!(list.peek(), Some(&2));
+            assert_eq!(list.peek_back(), Some(&2));
+
+            list.push(3);
+            list.push_front(1);
+            list.push(4);
+            assert_eq!(list.len(), 4);
+
+            assert_eq!(list.pop(), Some(1));
+            assert_eq!(list.pop(), Some(2));
+            assert_eq!(list.pop(), Some(3));
+            assert_eq!(list.pop(), Some(4));
+            assert_eq!(list.pop(), None);
+
+            list.push_front(5);
+            list.push(6);
+            assert_eq!(list.pop(), Some(5));
+            assert_eq!(list.pop(), Some(6));
+        }
+
+        #[test]
+        fn append() {
+            let mut list: Queue<i32> = (1..=2).collect();
+            let mut other: Queue<i32> = (3..=4).collect();
+
+            list.append(&mut other);
+            assert!(other.is_empty());
+            assert_eq!(other.pop(), None);
+            assert_eq!(list.len(), 4);
+            assert_eq!(list.peek_back(), Some(&4));
+
+            // Both queues must still work after the splice
+            list.push(5);
+            other.push(6);
+            assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+            assert_eq!(other.pop(), Some(6));
+        }
+
+        #[test]
+        fn append_empty_queues() {
+            let mut list = Queue::new();
+            let mut other: Queue<i32> = (1..=2).collect();
+
+            list.append(&mut other);
+            assert!(other.is_empty());
+            assert_eq!(list.peek(), Some(&1));
+            assert_eq!(list.peek_back(), Some(&2));
+
+            list.append(&mut other);
+            assert_eq!(list.len(), 2);
+            list.push(3);
+            assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        }
+
         #[test]
         fn into_iter() {
             let mut list = Queue::new();
@@ -280,6 +903,53 @@ mod singly_linked_queue {
             assert_eq!(iter.next(), None);
         }
 
+        #[test]
+        fn for_loops() {
+            let mut list = Queue::new();
+            list.push(1); list.push(2); list.push(3);
+
+            for elem in &mut list {
+                *elem *= 10;
+            }
+
+            let mut borrowed = Vec::new();
+            for elem in &list {
+                borrowed.push(*elem);
+            }
+            assert_eq!(borrowed, vec![10, 20, 30]);
+
+            let mut owned = Vec::new();
+            for elem in list {
+                owned.push(elem);
+            }
+            assert_eq!(owned, vec![10, 20, 30]);
+        }
+
+        #[test]
+        fn size_hint() {
+            let mut list = Queue::new();
+            list.push(1); list.push(2); list.push(3);
+
+            let mut iter = list.iter();
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+            assert_eq!(iter.len(), 3);
+            iter.next();
+            assert_eq!(iter.len(), 2);
+            iter.next(); iter.next();
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.len(), 0);
+
+            let mut iter = list.iter_mut();
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+            iter.next();
+            assert_eq!(iter.len(), 2);
+
+            let mut iter = list.into_iter();
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+            iter.next();
+            assert_eq!(iter.len(), 2);
+        }
+
         #[test]
         fn miri_food() {
             let mut list = Queue::new();
@@ -317,5 +987,198 @@ mod singly_linked_queue {
 
             // Drop it on the ground and let the dtor exercise itself
         }
+
+        #[test]
+        fn drain_filter_head() {
+            let mut list: Queue<i32> = (1..=4).collect();
+            assert_eq!(list.drain_filter(|x| *x == 1), vec![1]);
+            assert_eq!(list.peek(), Some(&2));
+            assert_eq!(list.len(), 3);
+            list.push(5);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+        }
+
+        #[test]
+        fn drain_filter_tail() {
+            let mut list: Queue<i32> = (1..=4).collect();
+            assert_eq!(list.drain_filter(|x| *x == 4), vec![4]);
+            assert_eq!(list.peek_back(), Some(&3));
+            assert_eq!(list.len(), 3);
+            // `tail` must now be the old second-to-last node, or this push would be lost
+            list.push(5);
+            assert_eq!(list.peek_back(), Some(&5));
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 5]);
+        }
+
+        #[test]
+        fn drain_filter_interior() {
+            let mut list: Queue<i32> = (1..=6).collect();
+            assert_eq!(list.drain_filter(|x| *x % 2 == 0 && *x != 6), vec![2, 4]);
+            assert_eq!(list.len(), 4);
+            assert_eq!(list.peek(), Some(&1));
+            assert_eq!(list.peek_back(), Some(&6));
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3, 5, 6]);
+        }
+
+        #[test]
+        fn drain_filter_everything() {
+            let mut list: Queue<i32> = (1..=3).collect();
+            assert_eq!(list.drain_filter(|_| true), vec![1, 2, 3]);
+            assert!(list.is_empty());
+            assert_eq!(list.len(), 0);
+            assert_eq!(list.peek_back(), None);
+
+            list.push(7);
+            assert_eq!(list.peek(), Some(&7));
+            assert_eq!(list.peek_back(), Some(&7));
+            assert_eq!(list.drain_filter(|_| false), Vec::<i32>::new());
+            assert_eq!(list.pop(), Some(7));
+        }
+
+        #[test]
+        fn invariants_push_pop() {
+            let mut list = Queue::new();
+            list.assert_invariants();
+
+            list.push(1);
+            list.assert_invariants();
+            list.push(2);
+            list.assert_invariants();
+
+            list.pop();
+            list.assert_invariants();
+            list.pop();
+            list.assert_invariants();
+
+            // Popping an empty queue leaves it alone
+            list.pop();
+            list.assert_invariants();
+
+            list.push_front(3);
+            list.assert_invariants();
+            list.push_front(4);
+            list.assert_invariants();
+            list.clear();
+            list.assert_invariants();
+        }
+
+        #[test]
+        fn invariants_append_and_drain() {
+            let mut list: Queue<i32> = Queue::new();
+            let mut other: Queue<i32> = (1..=3).collect();
+            other.assert_invariants();
+
+            list.append(&mut other);
+            list.assert_invariants();
+            other.assert_invariants();
+
+            list.append(&mut other);
+            list.assert_invariants();
+
+            list.drain_filter(|x| *x == 3);
+            list.assert_invariants();
+            list.drain_filter(|x| *x == 1);
+            list.assert_invariants();
+            list.drain_filter(|_| true);
+            list.assert_invariants();
+
+            list.push(4);
+            list.assert_invariants();
+        }
+
+        #[test]
+        fn retain_evens() {
+            let mut list: Queue<i32> = (1..=6).collect();
+            list.retain(|x| *x % 2 == 0);
+            list.assert_invariants();
+            assert_eq!(list.len(), 3);
+            assert_eq!(list.peek(), Some(&2));
+            assert_eq!(list.peek_back(), Some(&6));
+            list.push(8);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 4, 6, 8]);
+        }
+
+        #[test]
+        fn retain_nothing() {
+            let mut list: Queue<i32> = (1..=3).collect();
+            list.retain(|_| false);
+            list.assert_invariants();
+            assert!(list.is_empty());
+            list.push(4);
+            assert_eq!(list.pop(), Some(4));
+        }
+
+        #[test]
+        fn retain_everything() {
+            let mut list: Queue<i32> = (1..=3).collect();
+            list.retain(|_| true);
+            list.assert_invariants();
+            assert_eq!(list, (1..=3).collect());
+        }
+
+        #[test]
+        fn from_vec() {
+            let mut list = Queue::from(vec![1, 2, 3]);
+            list.assert_invariants();
+            assert_eq!(list.len(), 3);
+            assert_eq!(list.pop(), Some(1));
+            assert_eq!(list.pop(), Some(2));
+            assert_eq!(list.pop(), Some(3));
+            assert_eq!(list.pop(), None);
+        }
+
+        #[test]
+        fn vec_round_trip() {
+            let list: Queue<i32> = vec![1, 2, 3].into();
+            let v: Vec<i32> = list.into();
+            assert_eq!(v, vec![1, 2, 3]);
+
+            let mut list = Queue::new();
+            list.push(4); list.push(5); list.push(6);
+            assert_eq!(Vec::from(list.clone()), vec![4, 5, 6]);
+            assert_eq!(Queue::from(Vec::from(list.clone())), list);
+
+            assert_eq!(Vec::from(Queue::<i32>::new()), Vec::<i32>::new());
+        }
+
+        #[test]
+        fn has_cycle() {
+            let mut list = Queue::new();
+            assert!(!list.has_cycle());
+            list.push(1);
+            assert!(!list.has_cycle());
+            list.push(2); list.push(3);
+            assert!(!list.has_cycle());
+            list.pop();
+            assert!(!list.has_cycle());
+
+            // Deliberately loop the tail back to the head, then undo it before the queue is dropped
+            unsafe {
+                (*list.tail).next = list.head;
+                assert!(list.has_cycle());
+                (*list.tail).next = std::ptr::null_mut();
+            }
+            assert!(!list.has_cycle());
+        }
+
+        #[test]
+        fn fused() {
+            let mut list: Queue<i32> = (1..=2).collect();
+
+            let mut iter = list.iter().fuse();
+            iter.next(); iter.next();
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+
+            let mut iter = list.iter_mut().fuse();
+            iter.next(); iter.next();
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+
+            let mut iter = list.into_iter().fuse();
+            iter.next(); iter.next();
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+        }
     }
-}
\ No newline at end of file
+}
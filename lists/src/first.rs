@@ -26,6 +26,7 @@
  */
 
 /// Imports
+use std::iter::FusedIterator;
 use std::mem;
 
 /// Abstraction for a singly linked list
@@ -48,6 +49,13 @@ struct Node {
     next: Link,
 }
 
+/// An iterator over the elements of a [List]
+/// # Remarks
+/// * Holds a reference to the next [Node] to yield, or [None] once the end of the list is reached
+pub struct Iter<'a> {
+    next: Option<&'a Node>,
+}
+
 impl List {
     /// Creates a new empty list
     /// # See Also
@@ -110,6 +118,183 @@ impl List {
         // a macro to avoid warnings until we implement the function
         // unimplemented!();
     }
+
+    /// Reverses the list in place, so the head becomes the tail
+    /// # Arguments
+    /// * `&mut self` - The list to reverse
+    /// # Remarks
+    /// * No nodes are allocated or cloned; each [Link] is moved with `mem::replace`
+    /// # Algorithm
+    /// 1. Take the whole chain out of the head, leaving [Link::Empty] behind
+    /// 2. While the chain has a node
+    ///     * Detach the rest of the chain from the node
+    ///     * Point the node's `next` at the reversed chain built so far
+    ///     * Make the node the head of the reversed chain
+    /// 3. Put the reversed chain back into the head
+    pub fn reverse(&mut self) {
+        let mut reversed = Link::Empty;
+        let mut cur_link = mem::replace(&mut self.head, Link::Empty);
+        while let Link::More(mut node) = cur_link {
+            cur_link = mem::replace(&mut node.next, reversed);
+            reversed = Link::More(node);
+        }
+        self.head = reversed;
+    }
+
+    /// Peeks at the value at the head of the list
+    /// # Returns
+    /// * [Some] - A reference to the value at the head of the list
+    /// * [None] - If the list is empty
+    /// # See Also
+    /// * [super::second::List::peek]
+    pub fn peek(&self) -> Option<&i32> {
+        match &self.head {
+            Link::Empty => None,
+            Link::More(node) => Some(&node.element),
+        }
+    }
+
+    /// Peeks at the value at the head of the list
+    /// # Returns
+    /// * [Some] - A mutable reference to the value at the head of the list
+    /// * [None] - If the list is empty
+    /// # Remarks
+    /// * This is a mutable version of [List::peek]
+    pub fn peek_mut(&mut self) -> Option<&mut i32> {
+        match &mut self.head {
+            Link::Empty => None,
+            Link::More(node) => Some(&mut node.element),
+        }
+    }
+
+    /// Gets a reference to the value at a position in the list
+    /// # Arguments
+    /// * `&self` - The list to look in
+    /// * `index`: [usize] - The position of the value, where `0` is the head of the list
+    /// # Returns
+    /// * [Some] - A reference to the value at `index`
+    /// * [None] - If `index` is past the end of the list
+    /// # Remarks
+    /// * There is no random access in a linked list, so we follow [Link::More] nodes `index` times from the head
+    pub fn get(&self, index: usize) -> Option<&i32> {
+        let mut cur_link = &self.head;
+        for _ in 0..index {
+            match cur_link {
+                Link::Empty => return None,
+                Link::More(node) => cur_link = &node.next,
+            }
+        }
+        match cur_link {
+            Link::Empty => None,
+            Link::More(node) => Some(&node.element),
+        }
+    }
+
+    /// Counts the number of elements in the list
+    /// # Arguments
+    /// * `&self` - The list to count
+    /// # Returns
+    /// * [usize] - The number of [Link::More] nodes in the list
+    /// # Remarks
+    /// * We don't cache a count, so this walks the entire chain and runs in O(n)
+    pub fn len(&self) -> usize {
+        let mut count = 0;
+        let mut cur_link = &self.head;
+        while let Link::More(node) = cur_link {
+            count += 1;
+            cur_link = &node.next;
+        }
+        count
+    }
+
+    /// Checks if the list has no elements
+    /// # Returns
+    /// * `true` - If the head of the list is [Link::Empty]
+    /// * `false` - Otherwise
+    /// # Remarks
+    /// * Only the head is inspected, so this runs in O(1)
+    pub fn is_empty(&self) -> bool {
+        matches!(self.head, Link::Empty)
+    }
+
+    /// Adds up every value in the list
+    /// # Returns
+    /// * [i32] - The sum of the values, or `0` for an empty list
+    pub fn sum(&self) -> i32 {
+        self.iter().sum()
+    }
+
+    /// Finds the largest value in the list
+    /// # Returns
+    /// * [Some] - The largest value
+    /// * [None] - If the list is empty
+    pub fn max(&self) -> Option<i32> {
+        self.iter().max().copied()
+    }
+
+    /// Finds the smallest value in the list
+    /// # Returns
+    /// * [Some] - The smallest value
+    /// * [None] - If the list is empty
+    pub fn min(&self) -> Option<i32> {
+        self.iter().min().copied()
+    }
+
+    /// Creates an iterator over the elements of the list without consuming it
+    /// # Returns
+    /// * [Iter] - An iterator yielding references to each element, from the head to the tail
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            next: match &self.head {
+                Link::Empty => None,
+                Link::More(node) => Some(node),
+            },
+        }
+    }
+}
+
+/// Implement the [Iterator] trait for [Iter]
+/// # Remarks
+/// * [Link] is an enum rather than an [Option], so we match on [Link::More] / [Link::Empty] to find the next node
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a i32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = match &node.next {
+                Link::Empty => None,
+                Link::More(next) => Some(next),
+            };
+            &node.element
+        })
+    }
+}
+
+/// Implement the [FusedIterator] trait for [Iter]
+/// # Remarks
+/// * Once `next` is [None] there is no node left to move to, so every later call returns [None] as well
+impl FusedIterator for Iter<'_> {}
+
+/// Implement the [Extend] trait for [List]
+/// # Remarks
+/// * Each value is pushed onto the head, so the last value extended ends up at the head
+impl Extend<i32> for List {
+    fn extend<I: IntoIterator<Item = i32>>(&mut self, iter: I) {
+        for element in iter {
+            self.push(element);
+        }
+    }
+}
+
+/// Implement the [FromIterator] trait for [List]
+/// # Remarks
+/// * Like [List::extend], this pushes each value, so the order is reversed: `(1..=3).collect::<List>()` has `3` at the head
+impl FromIterator<i32> for List {
+    fn from_iter<I: IntoIterator<Item = i32>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
 }
 
 /// Implement the [Drop] trait for [List]
@@ -159,4 +344,152 @@ mod test {
         assert_eq!(list.pop(), Some(1));
         assert_eq!(list.pop(), None);
     }
+
+    #[test]
+    fn reverse_empty_list() {
+        let mut list = List::new();
+        list.reverse();
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn reverse_single_element_list() {
+        let mut list = List::new();
+        list.push(1);
+        list.reverse();
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn reverse_list() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        list.reverse();
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn peek_empty_list() {
+        let list = List::new();
+        assert_eq!(list.peek(), None);
+    }
+
+    #[test]
+    fn peek_list() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.peek(), Some(&3));
+        assert_eq!(list.peek_mut(), Some(&mut 3));
+    }
+
+    #[test]
+    fn peek_mut_empty_list() {
+        let mut list = List::new();
+        assert_eq!(list.peek_mut(), None);
+    }
+
+    #[test]
+    fn peek_mut_list() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        if let Some(value) = list.peek_mut() { *value = 42 }
+        assert_eq!(list.peek(), Some(&42));
+        assert_eq!(list.pop(), Some(42));
+    }
+
+    #[test]
+    fn get() {
+        let mut list = List::new();
+        assert_eq!(list.get(0), None);
+
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.get(0), Some(&3));
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), Some(&1));
+        assert_eq!(list.get(3), None);
+        assert_eq!(list.get(10), None);
+    }
+
+    #[test]
+    fn len() {
+        let mut list = List::new();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.len(), 3);
+        assert!(!list.is_empty());
+
+        list.pop();
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn aggregates_empty_list() {
+        let list = List::new();
+        assert_eq!(list.sum(), 0);
+        assert_eq!(list.max(), None);
+        assert_eq!(list.min(), None);
+    }
+
+    #[test]
+    fn aggregates() {
+        let mut list = List::new();
+        list.push(4);
+        list.push(-7);
+        list.push(10);
+        list.push(-2);
+        assert_eq!(list.sum(), 5);
+        assert_eq!(list.max(), Some(10));
+        assert_eq!(list.min(), Some(-7));
+    }
+
+    #[test]
+    fn from_iterator() {
+        let mut list: List = (1..=3).collect();
+        assert_eq!(list.peek(), Some(&3));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn extend() {
+        let mut list = List::new();
+        list.push(1);
+        list.extend(vec![2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(1));
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
 }
@@ -9,6 +9,8 @@
 
 use std::rc::Rc;
 use std::cell::{Ref, RefCell, RefMut};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
 
 /// A bad doubly linked list
 pub struct DoublyLinkedList<T> {
@@ -32,7 +34,39 @@ struct Node<T> {
 }
 
 /// Implementing `IntoIterator` for [`DoublyLinkedList`]
-pub struct IntoIterator<T>(DoublyLinkedList<T>);
+/// # Remarks
+/// - The list doesn't cache its length, so the iterator counts the [`Node`]s once up front and keeps its own `remaining` count
+pub struct IntoIterator<T> {
+    list: DoublyLinkedList<T>,
+    remaining: usize,
+}
+
+/// A cursor over a [`DoublyLinkedList`] that can edit the list in the middle
+/// # Remarks
+/// - The cursor is either on a [`Node`] or on the "ghost" position between the tail and the head
+/// - Holds the list mutably borrowed, plus a clone of the `Rc` of the current node
+pub struct Cursor<'a, T> {
+    list: &'a mut DoublyLinkedList<T>,
+    cur: Link<T>,
+}
+
+/// A borrowing iterator over a [`DoublyLinkedList`], from front to back
+/// # Remarks
+/// - Holds a clone of the `Rc` of the next [`Node`] to visit, so walking forward never has to keep a `Ref` to the previous node alive
+/// - The `PhantomData` ties the iterator to a shared borrow of the list, so the list can't be mutated while it's alive
+pub struct Iter<'a, T> {
+    next: Link<T>,
+    _list: PhantomData<&'a DoublyLinkedList<T>>,
+}
+
+/// A mutable borrowing iterator over a [`DoublyLinkedList`], from front to back
+/// # Remarks
+/// - Walks the list the same way as [`Iter`], but hands out `RefMut`s
+/// - The `PhantomData` ties the iterator to a mutable borrow of the list, so nothing else can touch the list while it's alive
+pub struct IterMut<'a, T> {
+    next: Link<T>,
+    _list: PhantomData<&'a mut DoublyLinkedList<T>>,
+}
 
 impl<T> Node<T> {
     /// Create a new node in the list
@@ -43,6 +77,26 @@ impl<T> Node<T> {
             next: None,
         }))
     }
+
+    /// Takes the element out of a node that has just been unlinked from the list
+    /// # Remarks
+    /// - Every `Rc` to a node is owned by the list itself: by `head`, `tail`, or a neighbouring node
+    /// - [`Iter`], [`IterMut`] and [`Cursor`] clone `Rc`s, but all implement `Drop`, so the list stays borrowed until they (and their clones) are gone
+    /// - [`Cursor::remove_current`] gives up its own clone before unwrapping the node it removes
+    /// - Once a node is unlinked, the `Rc` passed in is therefore the only one left and `try_unwrap` can't fail
+    fn into_elem(node: Rc<RefCell<Self>>) -> T {
+        match Rc::try_unwrap(node) {
+            Ok(node) => node.into_inner().elem,
+            Err(_) => unreachable!("a node unlinked from the list must have no other owners"),
+        }
+    }
+
+    /// Borrows the `RefCell` behind a node's `Rc` for `'a`, so [`Iter`] and [`IterMut`] can hand out `Ref`s that outlive their own clone
+    fn borrow_for<'a>(node: &Rc<RefCell<Self>>) -> &'a RefCell<Self> {
+        // SAFETY: only called by the iterators, which keep the list borrowed for `'a`; the list holds
+        // its own `Rc` to every node, so no node can be unlinked or dropped while this reference is alive
+        unsafe { &*Rc::as_ptr(node) }
+    }
 }
 
 impl<T> DoublyLinkedList<T> {
@@ -82,20 +136,36 @@ impl<T> DoublyLinkedList<T> {
 
     /// Pops a [`Node`] from the head of the list
     pub fn pop_front(&mut self) -> Option<T> {
-        self.head.take().map(|old_head| {
-            match old_head.borrow_mut().next.take() {
-                Some(new_head) => {
-                    new_head.borrow_mut().prev.take();
-                    self.head = Some(new_head);
-                }
-                None => {
-                    self.tail.take();
-                }
+        self.unlink_front().map(Node::into_elem)
+    }
+
+    /// Pops a [`Node`] from the head of the list, cloning its element out if the node is still shared
+    pub fn pop_front_cloned(&mut self) -> Option<T> where T: Clone {
+        self.unlink_front().map(|old_head| {
+            match Rc::try_unwrap(old_head) {
+                Ok(node) => node.into_inner().elem,
+                Err(node) => node.borrow().elem.clone(),
             }
-            Rc::try_unwrap(old_head).ok().unwrap().into_inner().elem
         })
     }
 
+    /// Unlinks the [`Node`] at the head of the list and returns its `Rc`, shared by [`DoublyLinkedList::pop_front`] and [`DoublyLinkedList::pop_front_cloned`]
+    /// # Remarks
+    /// - The new head's `prev` is cleared, and an emptied list has its `tail` cleared too, so the returned `Rc` is the only one the list gave up
+    fn unlink_front(&mut self) -> Link<T> {
+        let old_head = self.head.take()?;
+        match old_head.borrow_mut().next.take() {
+            Some(new_head) => {
+                new_head.borrow_mut().prev.take();
+                self.head = Some(new_head);
+            }
+            None => {
+                self.tail.take();
+            }
+        }
+        Some(old_head)
+    }
+
     /// Gets an immutable reference to the [`Node`] at the head of the list
     pub fn peek_front(&self) -> Option<Ref<T>> {
         self.head.as_ref().map(|node| {
@@ -131,7 +201,7 @@ impl<T> DoublyLinkedList<T> {
                     self.head.take();
                 }
             }
-            Rc::try_unwrap(old_tail).ok().unwrap().into_inner().elem
+            Node::into_elem(old_tail)
         })
     }
 
@@ -156,9 +226,167 @@ impl<T> DoublyLinkedList<T> {
         })
     }
 
+    /// Moves the [`Node`] at the head of the list to the tail
+    /// # Remarks
+    /// - No nodes are allocated or freed: the old head is unlinked from its successor and relinked after the old tail
+    /// - Empty and single-element lists are left as they are
+    pub fn rotate_left(&mut self) {
+        let (old_head, old_tail) = match (&self.head, &self.tail) {
+            (Some(head), Some(tail)) if !Rc::ptr_eq(head, tail) => (head.clone(), tail.clone()),
+            _ => return,
+        };
+
+        // The list has at least two nodes, so the head always has a successor
+        let new_head = old_head.borrow_mut().next.take().unwrap();
+        new_head.borrow_mut().prev.take();
+
+        old_head.borrow_mut().prev = Some(old_tail.clone());
+        old_tail.borrow_mut().next = Some(old_head.clone());
+
+        self.head = Some(new_head);
+        self.tail = Some(old_head);
+    }
+
+    /// Moves the [`Node`] at the tail of the list to the head
+    /// # Remarks
+    /// - The mirror image of [`DoublyLinkedList::rotate_left`]
+    /// - Each `borrow_mut()` is a temporary that ends with its statement, so no two borrows of the same node ever overlap
+    pub fn rotate_right(&mut self) {
+        let (old_head, old_tail) = match (&self.head, &self.tail) {
+            (Some(head), Some(tail)) if !Rc::ptr_eq(head, tail) => (head.clone(), tail.clone()),
+            _ => return,
+        };
+
+        // The list has at least two nodes, so the tail always has a predecessor
+        let new_tail = old_tail.borrow_mut().prev.take().unwrap();
+        new_tail.borrow_mut().next.take();
+
+        old_tail.borrow_mut().next = Some(old_head.clone());
+        old_head.borrow_mut().prev = Some(old_tail.clone());
+
+        self.tail = Some(new_tail);
+        self.head = Some(old_tail);
+    }
+
+    /// Swaps the elements at the head and the tail of the list in O(1)
+    /// # Remarks
+    /// - Only the `elem` fields are swapped; the [`Node`]s themselves stay where they are
+    /// - Empty and single-element lists are left as they are, which also avoids borrowing the same `RefCell` mutably twice
+    pub fn swap_ends(&mut self) {
+        if let (Some(head), Some(tail)) = (&self.head, &self.tail) {
+            if !Rc::ptr_eq(head, tail) {
+                std::mem::swap(&mut head.borrow_mut().elem, &mut tail.borrow_mut().elem);
+            }
+        }
+    }
+
+    /// Splits the list in two at `index`, returning everything from `index` onwards
+    /// # Remarks
+    /// - Behaves like `Vec::split_off`: `index == 0` moves the whole list out, `index >= len` returns an empty list
+    /// - The link between the two halves is cut in both directions, and each half gets its own `head` and `tail`
+    pub fn split_at(&mut self, index: usize) -> DoublyLinkedList<T> {
+        if index == 0 {
+            return DoublyLinkedList { head: self.head.take(), tail: self.tail.take() };
+        }
+
+        let mut cur = self.head.clone();
+        for _ in 0..index {
+            cur = match cur {
+                Some(node) => node.borrow().next.clone(),
+                None => break,
+            };
+        }
+
+        match cur {
+            Some(new_head) => {
+                // `index > 0`, so the split node always has a predecessor
+                let new_tail = new_head.borrow_mut().prev.take().unwrap();
+                new_tail.borrow_mut().next.take();
+                DoublyLinkedList {
+                    head: Some(new_head),
+                    tail: self.tail.replace(new_tail),
+                }
+            }
+            None => DoublyLinkedList::new(),
+        }
+    }
+
+    /// Clones the element at the head of the list
+    /// # Remarks
+    /// - Unlike [`DoublyLinkedList::peek_front`], the `RefCell` borrow is dropped before returning, so the value can be kept around freely
+    pub fn front_cloned(&self) -> Option<T> where T: Clone {
+        self.head.as_ref().map(|node| node.borrow().elem.clone())
+    }
+
+    /// Clones the element at the tail of the list
+    /// # Remarks
+    /// - Unlike [`DoublyLinkedList::peek_back`], the `RefCell` borrow is dropped before returning, so the value can be kept around freely
+    pub fn back_cloned(&self) -> Option<T> where T: Clone {
+        self.tail.as_ref().map(|node| node.borrow().elem.clone())
+    }
+
+    /// Counts the [`Node`]s in the list by walking from the head
+    /// # Remarks
+    /// - Each `borrow()` only lives for the statement that clones the next `Rc`, so no `Ref` is held while moving on
+    pub fn len(&self) -> usize {
+        let mut count = 0;
+        let mut cur = self.head.clone();
+        while let Some(node) = cur {
+            count += 1;
+            cur = node.borrow().next.clone();
+        }
+        count
+    }
+
+    /// Returns the index of the first element, counting from the head, for which `pred` returns `true`
+    /// # Remarks
+    /// - Like [`DoublyLinkedList::len`], each `borrow()` is a temporary that ends with its statement, so no `Ref` is held while `pred` runs on the next node or while moving on
+    pub fn position<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<usize> {
+        let mut index = 0;
+        let mut cur = self.head.clone();
+        while let Some(node) = cur {
+            if pred(&node.borrow().elem) {
+                return Some(index);
+            }
+            index += 1;
+            cur = node.borrow().next.clone();
+        }
+        None
+    }
+
+    /// Returns `true` if the list has no [`Node`]s
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
     /// Returns an iterator over the list
+    /// # Remarks
+    /// - Walks the list once to count it, so the iterator can report an exact size
     pub fn into_iterator(self) -> IntoIterator<T> {
-        IntoIterator(self)
+        let remaining = self.len();
+        IntoIterator { list: self, remaining }
+    }
+
+    /// Returns a [`Cursor`] positioned on the head of the list (or on the ghost position if the list is empty)
+    pub fn cursor_front(&mut self) -> Cursor<'_, T> {
+        let cur = self.head.clone();
+        Cursor { list: self, cur }
+    }
+
+    /// Returns a borrowing iterator over the list, from front to back
+    /// # Remarks
+    /// - Nodes live in `RefCell`s, so we can't hand out a plain `&T`; each item is a `Ref<T>` holding a shared borrow of its node
+    /// - Any number of these `Ref`s can be held at once, and all of them must be dropped before the list can be mutated again
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.clone(), _list: PhantomData }
+    }
+
+    /// Returns a mutable borrowing iterator over the list, from front to back
+    /// # Remarks
+    /// - Each item is a `RefMut<T>` holding a mutable borrow of its node, so elements can be changed in place
+    /// - The shared borrow used to find the next node ends before the `RefMut` is taken, and every `RefMut` borrows a different node, so holding on to earlier items never panics
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { next: self.head.clone(), _list: PhantomData }
     }
 }
 
@@ -168,16 +396,222 @@ impl<T> Drop for DoublyLinkedList<T> {
     }
 }
 
+/// Rebuilds the list from fresh [`Node`]s with repeated `push_back`, so the clone never shares node storage with the original
+impl<T: Clone> Clone for DoublyLinkedList<T> {
+    fn clone(&self) -> Self {
+        let mut list = DoublyLinkedList::new();
+        for elem in self.iter() {
+            list.push_back(elem.clone());
+        }
+        list
+    }
+}
+
+/// Compares two lists element by element, from front to back
+/// # Remarks
+/// - Both lists are walked together; each pair of `borrow()`s only lives for one comparison
+/// - Lists of differing lengths are never equal: one walk runs out before the other
+impl<T: PartialEq> PartialEq for DoublyLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let mut a = self.head.clone();
+        let mut b = other.head.clone();
+        loop {
+            match (a, b) {
+                (Some(x), Some(y)) => {
+                    if x.borrow().elem != y.borrow().elem {
+                        return false;
+                    }
+                    a = x.borrow().next.clone();
+                    b = y.borrow().next.clone();
+                }
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+}
+
+impl<T: Eq> Eq for DoublyLinkedList<T> {}
+
+/// Builds a list by calling `push_back` for each element, so the front is `v[0]` and the back is the last element
+impl<T> From<Vec<T>> for DoublyLinkedList<T> {
+    fn from(v: Vec<T>) -> Self {
+        v.into_iter().collect()
+    }
+}
+
+/// Builds a list by calling `push_back` for each item
+/// # Remarks
+/// - Unlike the stack lists, the order of the iterator is preserved: the first item ends up at the front
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    fn from_iter<I: std::iter::IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DoublyLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+/// Calls `push_back` for each item, preserving the order of the iterator
+impl<T> Extend<T> for DoublyLinkedList<T> {
+    fn extend<I: std::iter::IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+/// Lets the list be consumed by a `for` loop, delegating to [`DoublyLinkedList::into_iterator`]
+impl<T> std::iter::IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iterator()
+    }
+}
+
 impl<T> Iterator for IntoIterator<T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.pop_front()
+        let elem = self.list.pop_front()?;
+        self.remaining -= 1;
+        Some(elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
 }
 
 impl<T> DoubleEndedIterator for IntoIterator<T> {
     fn next_back(&mut self) -> Option<T> {
-        self.0.pop_back()
+        let elem = self.list.pop_back()?;
+        self.remaining -= 1;
+        Some(elem)
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIterator<T> {}
+
+impl<T> FusedIterator for IntoIterator<T> {}
+
+impl<T> Cursor<'_, T> {
+    /// Moves the cursor to the next [`Node`]
+    /// # Remarks
+    /// - Moving past the tail lands on the ghost position; moving on from there wraps around to the head
+    pub fn move_next(&mut self) {
+        self.cur = match self.cur.take() {
+            Some(node) => node.borrow().next.clone(),
+            None => self.list.head.clone(),
+        };
+    }
+
+    /// Gets an immutable reference to the element under the cursor, or `None` on the ghost position
+    pub fn current(&self) -> Option<Ref<'_, T>> {
+        self.cur.as_ref().map(|node| {
+            Ref::map(node.borrow(), |node| &node.elem)
+        })
+    }
+
+    /// Inserts an element right after the cursor, without moving the cursor
+    /// # Remarks
+    /// - On the ghost position, "after" is the front of the list
+    /// - If the cursor is on the tail, the new [`Node`] becomes the list's tail
+    pub fn insert_after(&mut self, elem: T) {
+        let cur = match &self.cur {
+            Some(cur) => cur,
+            None => {
+                self.list.push_front(elem);
+                return;
+            }
+        };
+
+        let new_node = Node::new(elem);
+        match cur.borrow_mut().next.take() {
+            Some(next) => {
+                next.borrow_mut().prev = Some(new_node.clone());
+                new_node.borrow_mut().next = Some(next);
+            }
+            None => {
+                self.list.tail = Some(new_node.clone());
+            }
+        }
+        new_node.borrow_mut().prev = Some(cur.clone());
+        cur.borrow_mut().next = Some(new_node);
+    }
+
+    /// Removes the [`Node`] under the cursor and returns its element, moving the cursor on to the next node
+    /// # Remarks
+    /// - Returns `None` and does nothing on the ghost position
+    /// - The neighbours (or the list's `head`/`tail`) are linked to each other before the node is unwrapped
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.cur.take()?;
+        let prev = node.borrow_mut().prev.take();
+        let next = node.borrow_mut().next.take();
+
+        match &next {
+            Some(next) => next.borrow_mut().prev = prev.clone(),
+            None => self.list.tail = prev.clone(),
+        }
+        match &prev {
+            Some(prev) => prev.borrow_mut().next = next.clone(),
+            None => self.list.head = next.clone(),
+        }
+
+        self.cur = next;
+        Some(Node::into_elem(node))
+    }
+}
+
+/// Dropping the cursor releases its `Rc`, for the same reason as [`Iter`]'s `Drop`
+impl<T> Drop for Cursor<'_, T> {
+    fn drop(&mut self) {
+        self.cur.take();
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = Ref<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            let node = Node::borrow_for(&node);
+            self.next = node.borrow().next.clone();
+            Ref::map(node.borrow(), |node| &node.elem)
+        })
+    }
+}
+
+/// `next` only ever moves forwards, so once it is `None` the iterator stays at the end
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Dropping the iterator releases its `Rc`
+/// # Remarks
+/// - `Rc` lets its referent dangle when dropped, so without an explicit `Drop` the borrow checker would let the list be mutated while an unused iterator still held a node
+impl<T> Drop for Iter<'_, T> {
+    fn drop(&mut self) {
+        self.next.take();
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = RefMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            let node = Node::borrow_for(&node);
+            self.next = node.borrow().next.clone();
+            RefMut::map(node.borrow_mut(), |node| &mut node.elem)
+        })
+    }
+}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// Dropping the iterator releases its `Rc`, for the same reason as [`Iter`]'s `Drop`
+impl<T> Drop for IterMut<'_, T> {
+    fn drop(&mut self) {
+        self.next.take();
     }
 }
 
@@ -252,6 +686,231 @@ fn peek() {
     assert_eq!(&mut *list.peek_back_mut().unwrap(), &mut 1);
 }
 
+#[test]
+fn front_back_cloned() {
+    let mut list = DoublyLinkedList::new();
+    assert_eq!(list.front_cloned(), None);
+    assert_eq!(list.back_cloned(), None);
+
+    list.push_front(1); list.push_front(2); list.push_front(3);
+
+    let front = list.front_cloned();
+    let back = list.back_cloned();
+    assert_eq!(front.as_ref(), list.peek_front().as_deref());
+    assert_eq!(back.as_ref(), list.peek_back().as_deref());
+
+    // No borrow is held, so the list can still be mutated
+    *list.peek_front_mut().unwrap() = 42;
+    assert_eq!(front, Some(3));
+    assert_eq!(back, Some(1));
+}
+
+#[test]
+fn pop_after_peeking_and_iterating() {
+    let mut list: DoublyLinkedList<i32> = (1..=4).collect();
+
+    // Values cloned out of the list and a partially used iterator must not keep any node alive
+    let front = list.front_cloned();
+    let back = list.back_cloned();
+    let mut iter = list.iter();
+    let first = *iter.next().unwrap();
+    drop(iter);
+
+    assert_eq!(list.pop_front(), front);
+    assert_eq!(list.pop_back(), back);
+    assert_eq!(first, 1);
+
+    let elems: Vec<i32> = list.iter().map(|elem| *elem).collect();
+    assert_eq!(elems, vec![2, 3]);
+    assert_eq!(list.pop_front(), Some(2));
+    assert_eq!(list.pop_back(), Some(3));
+    assert_eq!(list.pop_back(), None);
+}
+
+#[test]
+fn rotate_left() {
+    let mut list: DoublyLinkedList<i32> = (1..=4).collect();
+
+    list.rotate_left();
+    assert_eq!(list.iter().map(|elem| *elem).collect::<Vec<_>>(), vec![2, 3, 4, 1]);
+    assert_eq!(&*list.peek_back().unwrap(), &1);
+
+    list.rotate_left();
+    assert_eq!(&*list.peek_front().unwrap(), &3);
+    assert_eq!(&*list.peek_back().unwrap(), &2);
+
+    // Walking backwards checks the `prev` links too
+    assert_eq!(list.into_iterator().rev().collect::<Vec<_>>(), vec![2, 1, 4, 3]);
+}
+
+#[test]
+fn rotate_left_short_lists() {
+    let mut list = DoublyLinkedList::new();
+    list.rotate_left();
+    assert!(list.is_empty());
+
+    list.push_back(1);
+    list.rotate_left();
+    assert_eq!(list.pop_back(), Some(1));
+    assert_eq!(list.pop_front(), None);
+}
+
+#[test]
+fn rotate_right() {
+    let mut list: DoublyLinkedList<i32> = (1..=4).collect();
+
+    list.rotate_right();
+    assert_eq!(&*list.peek_front().unwrap(), &4);
+    assert_eq!(&*list.peek_back().unwrap(), &3);
+
+    list.rotate_right();
+    assert_eq!(&*list.peek_front().unwrap(), &3);
+    assert_eq!(&*list.peek_back().unwrap(), &2);
+
+    list.rotate_left();
+    assert_eq!(list.iter().map(|elem| *elem).collect::<Vec<_>>(), vec![4, 1, 2, 3]);
+    assert_eq!(list.into_iterator().rev().collect::<Vec<_>>(), vec![3, 2, 1, 4]);
+}
+
+#[test]
+fn rotate_right_short_lists() {
+    let mut list = DoublyLinkedList::new();
+    list.rotate_right();
+    assert!(list.is_empty());
+
+    list.push_back(1);
+    list.rotate_right();
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_back(), None);
+}
+
+#[test]
+fn cursor_insert_after() {
+    let mut list: DoublyLinkedList<i32> = vec![1, 3].into_iter().collect();
+
+    let mut cursor = list.cursor_front();
+    assert_eq!(cursor.current().as_deref(), Some(&1));
+    cursor.insert_after(2);
+    assert_eq!(cursor.current().as_deref(), Some(&1));
+
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current().as_deref(), Some(&3));
+    cursor.insert_after(4);
+
+    cursor.move_next();
+    cursor.move_next();
+    assert!(cursor.current().is_none());
+    cursor.insert_after(0);
+    drop(cursor);
+
+    assert_eq!(&*list.peek_back().unwrap(), &4);
+    assert_eq!(list.iter().map(|elem| *elem).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    assert_eq!(list.into_iterator().rev().collect::<Vec<_>>(), vec![4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn cursor_remove_current() {
+    let mut list: DoublyLinkedList<i32> = (1..=4).collect();
+
+    let mut cursor = list.cursor_front();
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), Some(2));
+    assert_eq!(cursor.current().as_deref(), Some(&3));
+
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), Some(4));
+    assert!(cursor.current().is_none());
+    assert_eq!(cursor.remove_current(), None);
+
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), Some(1));
+    assert_eq!(cursor.current().as_deref(), Some(&3));
+    drop(cursor);
+
+    assert_eq!(list.len(), 1);
+    assert_eq!(&*list.peek_front().unwrap(), &3);
+    assert_eq!(&*list.peek_back().unwrap(), &3);
+    assert_eq!(list.pop_back(), Some(3));
+    assert!(list.is_empty());
+}
+
+#[test]
+fn cursor_empty_list() {
+    let mut list = DoublyLinkedList::new();
+
+    let mut cursor = list.cursor_front();
+    assert!(cursor.current().is_none());
+    cursor.move_next();
+    assert!(cursor.current().is_none());
+    cursor.insert_after(1);
+    cursor.move_next();
+    assert_eq!(cursor.current().as_deref(), Some(&1));
+    assert_eq!(cursor.remove_current(), Some(1));
+    drop(cursor);
+
+    assert!(list.is_empty());
+    list.push_back(2);
+    assert_eq!(list.pop_front(), Some(2));
+}
+
+#[test]
+fn split_at() {
+    let mut list: DoublyLinkedList<i32> = (1..=5).collect();
+    let mut back = list.split_at(2);
+
+    assert_eq!(&*list.peek_front().unwrap(), &1);
+    assert_eq!(&*list.peek_back().unwrap(), &2);
+    assert_eq!(&*back.peek_front().unwrap(), &3);
+    assert_eq!(&*back.peek_back().unwrap(), &5);
+
+    assert_eq!(list.pop_back(), Some(2));
+    assert_eq!(list.pop_back(), Some(1));
+    assert_eq!(list.pop_back(), None);
+    assert_eq!(back.pop_front(), Some(3));
+    assert_eq!(back.into_iterator().rev().collect::<Vec<_>>(), vec![5, 4]);
+}
+
+#[test]
+fn split_at_boundaries() {
+    let mut list: DoublyLinkedList<i32> = (1..=3).collect();
+
+    assert!(list.split_at(3).is_empty());
+    assert!(list.split_at(10).is_empty());
+    assert_eq!(list.len(), 3);
+
+    let back = list.split_at(0);
+    assert!(list.is_empty());
+    assert!(list.peek_back().is_none());
+    assert_eq!(back.into_iterator().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+    let mut list: DoublyLinkedList<i32> = (1..=3).collect();
+    let back = list.split_at(2);
+    assert_eq!(&*back.peek_front().unwrap(), &3);
+    assert_eq!(&*back.peek_back().unwrap(), &3);
+    assert_eq!(&*list.peek_back().unwrap(), &2);
+}
+
+#[test]
+fn len() {
+    let mut list = DoublyLinkedList::new();
+    assert!(list.is_empty());
+    assert_eq!(list.len(), 0);
+
+    list.push_front(2); list.push_back(3); list.push_front(1); list.push_back(4);
+    assert!(!list.is_empty());
+    assert_eq!(list.len(), 4);
+
+    list.pop_back();
+    list.pop_front();
+    assert_eq!(list.len(), 2);
+
+    list.pop_front();
+    list.pop_front();
+    assert!(list.is_empty());
+    assert_eq!(list.len(), 0);
+}
+
 #[test]
 fn into_iterator() {
     let mut list = DoublyLinkedList::new();
@@ -264,3 +923,233 @@ fn into_iterator() {
     assert_eq!(iter.next_back(), None);
     assert_eq!(iter.next(), None);
 }
+
+#[test]
+fn clone() {
+    let mut list = DoublyLinkedList::new();
+    list.push_back(1); list.push_back(2); list.push_back(3);
+
+    let mut cloned = list.clone();
+    *cloned.peek_front_mut().unwrap() = 42;
+    assert_eq!(&*cloned.peek_front().unwrap(), &42);
+    assert_eq!(&*list.peek_front().unwrap(), &1);
+
+    assert_eq!(cloned.pop_back(), Some(3));
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_front(), Some(2));
+    assert_eq!(list.pop_front(), Some(3));
+}
+
+#[test]
+fn from_iterator() {
+    let mut list: DoublyLinkedList<i32> = (0..5).collect();
+    for i in 0..5 {
+        assert_eq!(list.pop_front(), Some(i));
+    }
+    assert_eq!(list.pop_front(), None);
+}
+
+#[test]
+fn extend() {
+    let mut list = DoublyLinkedList::new();
+    list.push_back(1);
+    list.extend(vec![2, 3]);
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_front(), Some(2));
+    assert_eq!(list.pop_front(), Some(3));
+    assert_eq!(list.pop_front(), None);
+}
+
+#[test]
+fn for_loop() {
+    let mut list = DoublyLinkedList::new();
+    list.push_back(1); list.push_back(2); list.push_back(3);
+
+    let mut elems = Vec::new();
+    for elem in list {
+        elems.push(elem);
+    }
+    assert_eq!(elems, vec![1, 2, 3]);
+
+    let mut list = DoublyLinkedList::new();
+    list.push_back(1); list.push_back(2); list.push_back(3);
+
+    let mut elems = Vec::new();
+    for elem in list.into_iter().rev() {
+        elems.push(elem);
+    }
+    assert_eq!(elems, vec![3, 2, 1]);
+}
+
+#[test]
+fn iter() {
+    let mut list = DoublyLinkedList::new();
+    assert!(list.iter().next().is_none());
+
+    list.push_back(1); list.push_back(2); list.push_back(3);
+
+    let elems: Vec<Ref<i32>> = list.iter().collect();
+    assert_eq!(elems.len(), 3);
+    assert_eq!(*elems[0], 1);
+    assert_eq!(*elems[1], 2);
+    assert_eq!(*elems[2], 3);
+    drop(elems);
+
+    // The list is still usable once every `Ref` is gone
+    assert_eq!(list.pop_front(), Some(1));
+}
+
+#[test]
+fn swap_ends() {
+    let mut list: DoublyLinkedList<i32> = (1..=4).collect();
+    list.swap_ends();
+    assert_eq!(&*list.peek_front().unwrap(), &4);
+    assert_eq!(&*list.peek_back().unwrap(), &1);
+    assert_eq!(list.pop_front(), Some(4));
+    assert_eq!(list.pop_front(), Some(2));
+    assert_eq!(list.pop_front(), Some(3));
+    assert_eq!(list.pop_front(), Some(1));
+}
+
+#[test]
+fn swap_ends_short_lists() {
+    let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+    list.swap_ends();
+    assert!(list.is_empty());
+
+    list.push_back(1);
+    list.swap_ends();
+    assert_eq!(&*list.peek_front().unwrap(), &1);
+    assert_eq!(&*list.peek_back().unwrap(), &1);
+
+    list.push_back(2);
+    list.swap_ends();
+    assert_eq!(list.pop_front(), Some(2));
+    assert_eq!(list.pop_front(), Some(1));
+}
+
+#[test]
+fn into_iterator_len() {
+    let list: DoublyLinkedList<i32> = (1..=4).collect();
+
+    let mut iter = list.into_iterator();
+    assert_eq!(iter.len(), 4);
+    iter.next();
+    assert_eq!(iter.len(), 3);
+    iter.next_back();
+    assert_eq!(iter.len(), 2);
+    iter.next(); iter.next();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.len(), 0);
+
+    assert_eq!(DoublyLinkedList::<i32>::new().into_iterator().len(), 0);
+}
+
+#[test]
+fn pop_front_cloned() {
+    let mut list: DoublyLinkedList<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+
+    // No public method hands out node `Rc`s, so reach into the private field to hold a second handle to the head node
+    let handle = list.head.clone().unwrap();
+    assert_eq!(list.pop_front_cloned(), Some("a".to_string()));
+    assert_eq!(handle.borrow().elem, "a");
+    assert!(handle.borrow().next.is_none());
+
+    assert_eq!(&*list.peek_front().unwrap(), "b");
+    assert_eq!(list.pop_front_cloned(), Some("b".to_string()));
+    assert_eq!(list.pop_front_cloned(), None);
+    assert!(list.is_empty());
+    assert!(list.tail.is_none());
+}
+
+#[test]
+fn iter_mut() {
+    let mut list: DoublyLinkedList<i32> = (1..=3).collect();
+    for mut elem in list.iter_mut() {
+        *elem *= 10;
+    }
+
+    let elems: Vec<i32> = list.iter().map(|elem| *elem).collect();
+    assert_eq!(elems, vec![10, 20, 30]);
+
+    // Holding every `RefMut` at once is fine, since each borrows a different node
+    let held: Vec<RefMut<i32>> = list.iter_mut().collect();
+    assert_eq!(held.len(), 3);
+    drop(held);
+
+    assert_eq!(list.pop_back(), Some(30));
+}
+
+#[test]
+fn position() {
+    let list: DoublyLinkedList<i32> = (1..=5).collect();
+    assert_eq!(list.position(|elem| *elem == 3), Some(2));
+    assert_eq!(list.position(|elem| *elem > 4), Some(4));
+    assert_eq!(list.position(|elem| *elem % 2 == 0), Some(1));
+    assert_eq!(list.position(|elem| *elem > 10), None);
+    assert_eq!(DoublyLinkedList::<i32>::new().position(|_| true), None);
+}
+
+#[test]
+fn eq() {
+    let mut front_pushed = DoublyLinkedList::new();
+    front_pushed.push_front(3); front_pushed.push_front(2); front_pushed.push_front(1);
+
+    let mut back_pushed = DoublyLinkedList::new();
+    back_pushed.push_back(1); back_pushed.push_back(2); back_pushed.push_back(3);
+
+    assert!(front_pushed == back_pushed);
+    assert!(front_pushed == front_pushed);
+    assert!(DoublyLinkedList::<i32>::new() == DoublyLinkedList::new());
+}
+
+#[test]
+fn ne() {
+    let list: DoublyLinkedList<i32> = (1..=3).collect();
+    assert!(list != (1..=2).collect());
+    assert!(list != (1..=4).collect());
+    assert!(list != vec![1, 5, 3].into_iter().collect());
+    assert!(list != DoublyLinkedList::new());
+}
+
+#[test]
+fn from_vec() {
+    let mut list = DoublyLinkedList::from(vec![1, 2, 3, 4]);
+    assert_eq!(&*list.peek_front().unwrap(), &1);
+    assert_eq!(&*list.peek_back().unwrap(), &4);
+
+    // Popping from both ends walks the `next` and `prev` links towards each other
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_back(), Some(4));
+    assert_eq!(list.pop_back(), Some(3));
+    assert_eq!(list.pop_front(), Some(2));
+    assert_eq!(list.pop_back(), None);
+    assert_eq!(list.pop_front(), None);
+
+    assert!(DoublyLinkedList::<i32>::from(Vec::new()).is_empty());
+}
+
+#[test]
+fn fused() {
+    let mut list: DoublyLinkedList<i32> = (1..=2).collect();
+
+    let mut iter = list.iter().fuse();
+    iter.next(); iter.next();
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+    drop(iter);
+
+    let mut iter = list.iter_mut().fuse();
+    iter.next(); iter.next();
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+    drop(iter);
+
+    let mut iter = list.into_iterator().fuse();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
@@ -1,5 +1,7 @@
 ﻿pub struct List<T> {
     head: Link<T>,
+    // Cached number of nodes, kept up to date by every method that links or unlinks nodes
+    len: usize,
 }
 
 type Link<T> = Option<Box<Node<T>>>;
@@ -14,12 +16,19 @@ struct Node<T> {
 pub struct IntoIterator<T>(List<T>);
 
 // Iter is generic over *some* lifetime, it doesn't care
+// A singly linked list can't be walked backwards, so the nodes are collected up front to support `next_back`
 pub struct Iter<'a, T> {
-    next: Option<&'a Node<T>>,
+    nodes: std::vec::IntoIter<&'a Node<T>>,
 }
 
 pub struct IteratorMutable<'a, T> {
     next: Option<&'a mut Node<T>>,
+    len: usize,
+}
+
+// Drain pops from the head of the borrowed list, and empties whatever is left when dropped
+pub struct Drain<'a, T> {
+    list: &'a mut List<T>,
 }
 
 impl<T> Default for List<T> {
@@ -30,7 +39,36 @@ impl<T> Default for List<T> {
 
 impl<T> List<T> {
     pub fn new() -> Self {
-        List { head: None }
+        List { head: None, len: 0 }
+    }
+
+    /// Builds a list from a [Vec], preserving its order so `v[0]` ends up at the head
+    /// # Remarks
+    /// * Pushing reverses the order, so the vec is pushed back to front
+    pub fn from_vec(v: Vec<T>) -> List<T> {
+        v.into_iter().rev().collect()
+    }
+
+    /// Joins `lists` head-to-tail into one list, consuming them
+    /// # Remarks
+    /// * The first list's head becomes the result's head, and each list's nodes are relinked after the previous list's tail
+    /// * A cursor to the last `next` link is kept across lists, so every node is visited once and the whole join is O(total)
+    pub fn concat(lists: Vec<List<T>>) -> List<T> {
+        let mut result = List::new();
+        let mut tail = &mut result.head;
+        for mut list in lists {
+            result.len += list.len;
+            *tail = list.head.take();
+            while let Some(node) = tail {
+                tail = &mut node.next;
+            }
+        }
+        result
+    }
+
+    /// Consumes the list and collects its elements into a [Vec], from head to tail
+    pub fn into_vec(self) -> Vec<T> {
+        self.into_iterator().collect()
     }
 
     pub fn push(&mut self, elem: T) {
@@ -40,15 +78,43 @@ impl<T> List<T> {
         });
 
         self.head = Some(new_node);
+        self.len += 1;
+    }
+
+    /// Pushes an element onto the tail of the list
+    /// # Remarks
+    /// * O(n)
+    pub fn push_back(&mut self, elem: T) {
+        *self.last_link() = Some(Box::new(Node { elem, next: None }));
+        self.len += 1;
     }
 
     pub fn pop(&mut self) -> Option<T> {
         self.head.take().map(|node| {
             self.head = node.next;
+            self.len -= 1;
             node.elem
         })
     }
     
+    /// Pops the element at the tail of the list
+    /// # Returns
+    /// * [Some] - The last element of the list
+    /// * [None] - If the list is empty
+    /// # Remarks
+    /// * We walk to the link that owns the tail node (the second-to-last node's `next`, or `head` for a single element) and take it, leaving `None` behind
+    /// * Like [List::push_back] this is O(n)
+    pub fn pop_back(&mut self) -> Option<T> {
+        let mut cur_link = &mut self.head;
+        while cur_link.as_ref()?.next.is_some() {
+            cur_link = &mut cur_link.as_mut().unwrap().next;
+        }
+        cur_link.take().map(|node| {
+            self.len -= 1;
+            node.elem
+        })
+    }
+
     /// Peek at the first element (head) of the list, if it exists
     /// # Returns
     /// * [Some] - A reference to the first element of the list
@@ -74,11 +140,405 @@ impl<T> List<T> {
         })
     }
 
+    /// Peek at the last element (tail) of the list, if it exists
+    /// # Returns
+    /// * [Some] - A reference to the last element of the list
+    /// * [None] - If the list is empty
+    /// # Remarks
+    /// * O(n)
+    pub fn last(&self) -> Option<&T> {
+        let mut node = self.head.as_deref()?;
+        while let Some(next) = node.next.as_deref() {
+            node = next;
+        }
+        Some(&node.elem)
+    }
+
+    /// Peek at the last element (tail) of the list, if it exists
+    /// # Returns
+    /// * [Some] - A mutable reference to the last element of the list
+    /// * [None] - If the list is empty
+    /// # Remarks
+    /// * This is a mutable version of [List::last]
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        let mut node = self.head.as_deref_mut()?;
+        while node.next.is_some() {
+            node = node.next.as_deref_mut().unwrap();
+        }
+        Some(&mut node.elem)
+    }
+
+    /// Gets a reference to the element at `index`, counting from the head (index `0`)
+    /// # Returns
+    /// * [Some] - A reference to the element at `index`
+    /// * [None] - If `index` is past the end of the list
+    /// # Remarks
+    /// * There is no random access, so this walks `index` nodes from the head
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.elems().nth(index)
+    }
+
+    /// Swaps the elements at indices `i` and `j`, counting from the head
+    /// # Remarks
+    /// * Only the `elem` fields are swapped; the nodes stay where they are
+    /// * The node at the lower index is split into its `elem` and `next` fields, so the walk to the higher index can carry on from `next` while `elem` is still borrowed
+    /// * Panics if either index is out of range
+    pub fn swap(&mut self, i: usize, j: usize) {
+        for index in [i, j] {
+            assert!(index < self.len, "index out of bounds: the len is {} but the index is {}", self.len, index);
+        }
+        if i == j {
+            return;
+        }
+        let (lo, hi) = if i < j { (i, j) } else { (j, i) };
+
+        // Both indices are in range, so every node walked to below exists
+        let mut node = self.head.as_deref_mut().unwrap();
+        for _ in 0..lo {
+            node = node.next.as_deref_mut().unwrap();
+        }
+        let Node { elem: first, next } = node;
+
+        let mut other = next.as_deref_mut().unwrap();
+        for _ in 0..hi - lo - 1 {
+            other = other.next.as_deref_mut().unwrap();
+        }
+        std::mem::swap(first, &mut other.elem);
+    }
+
+    /// Inserts `elem` so that it ends up at `index`, counting from the head
+    /// # Remarks
+    /// * `index == 0` is the same as [List::push], and `index == len` appends at the tail
+    /// * Panics if `index` is greater than the length of the list
+    pub fn insert(&mut self, index: usize, elem: T) {
+        assert!(index <= self.len, "insertion index (is {}) should be <= len (is {})", index, self.len);
+        let link = self.link_at(index);
+        let next = link.take();
+        *link = Some(Box::new(Node { elem, next }));
+        self.len += 1;
+    }
+
+    /// Unlinks the node at `index`, counting from the head, and returns its element
+    /// # Returns
+    /// * [Some] - The removed element
+    /// * [None] - If `index` is past the end of the list, in which case nothing changes
+    /// # Remarks
+    /// * The link pointing at the node (its predecessor's `next`, or `head` for index `0`) is re-pointed at the node after it
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let link = self.link_at(index);
+        let node = link.take()?;
+        *link = node.next;
+        self.len -= 1;
+        Some(node.elem)
+    }
+
+    /// Returns `true` if any element of the list is equal to `target`
+    /// # Remarks
+    /// * Stops walking the list as soon as a match is found
+    pub fn contains(&self, target: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.elems().any(|elem| elem == target)
+    }
+
+    /// Counts the elements for which `pred` returns `true`, without allocating
+    pub fn count_matching<F: Fn(&T) -> bool>(&self, pred: F) -> usize {
+        self.elems().filter(|elem| pred(elem)).count()
+    }
+
+    /// Combines every element into a single value, from head to tail
+    /// # Remarks
+    /// * `f` is called with the accumulator so far and a reference to each element, starting from `init`
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.elems().fold(init, f)
+    }
+
+    /// Calls `f` on a reference to each element, from head to tail
+    pub fn for_each<F: FnMut(&T)>(&self, f: F) {
+        self.elems().for_each(f)
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, preserving their order
+    /// # Remarks
+    /// * The chain is taken apart one node at a time; kept nodes are relinked behind a cursor to the last `next` link, rejected nodes are freed
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut cur_link = self.head.take();
+        let mut tail = &mut self.head;
+        let mut kept = 0;
+        while let Some(mut node) = cur_link {
+            cur_link = node.next.take();
+            if f(&node.elem) {
+                tail = &mut tail.insert(node).next;
+                kept += 1;
+            }
+        }
+        self.len = kept;
+    }
+
+    /// Removes consecutive repeated elements, keeping the first of each run (like `Vec::dedup`)
+    /// # Remarks
+    /// * Only adjacent duplicates are removed, so `[1, 2, 1]` is left as it is
+    /// * A duplicate node is unlinked by pointing its predecessor's `next` past it, and is freed straight away
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        let mut cur_node = self.head.as_deref_mut();
+        while let Some(node) = cur_node {
+            while node.next.as_ref().is_some_and(|next| next.elem == node.elem) {
+                let mut duplicate = node.next.take().unwrap();
+                node.next = duplicate.next.take();
+                self.len -= 1;
+            }
+            cur_node = node.next.as_deref_mut();
+        }
+    }
+
+    /// Consumes the list and splits it into the elements for which `pred` returns `true` and those for which it returns `false`
+    /// # Remarks
+    /// * Both lists keep the relative order the elements had in `self`
+    /// * Like [List::retain], the chain is taken apart one node at a time and each node is relinked behind a cursor to the last `next` link of its new list, so nothing is allocated or cloned
+    pub fn partition<F: FnMut(&T) -> bool>(mut self, mut pred: F) -> (List<T>, List<T>) {
+        let mut matched = List::new();
+        let mut unmatched = List::new();
+        let mut matched_tail = &mut matched.head;
+        let mut unmatched_tail = &mut unmatched.head;
+        let mut matched_len = 0;
+
+        let mut cur_link = self.head.take();
+        while let Some(mut node) = cur_link {
+            cur_link = node.next.take();
+            if pred(&node.elem) {
+                matched_tail = &mut matched_tail.insert(node).next;
+                matched_len += 1;
+            } else {
+                unmatched_tail = &mut unmatched_tail.insert(node).next;
+            }
+        }
+
+        matched.len = matched_len;
+        unmatched.len = self.len - matched_len;
+        (matched, unmatched)
+    }
+
+    /// Builds a new list by applying `f` to each element
+    /// # Remarks
+    /// * The order is preserved, so the new head is `f` applied to the old head
+    /// * Pushing each result would reverse the list, so instead we keep a cursor to the last `next` link and build forwards
+    pub fn map<U, F: FnMut(&T) -> U>(&self, mut f: F) -> List<U> {
+        let mut list = List::new();
+        let mut tail = &mut list.head;
+        for elem in self.elems() {
+            let node = tail.insert(Box::new(Node { elem: f(elem), next: None }));
+            tail = &mut node.next;
+        }
+        list.len = self.len;
+        list
+    }
+
+    /// Returns the number of elements in the list
+    /// # Remarks
+    /// * The count is cached and updated whenever nodes are linked or unlinked, so this is O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list has no elements
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Reverses the list in place, so the head becomes the tail and vice versa
+    /// # Remarks
+    /// * No nodes are allocated; each node is taken off the old chain and its `next` is re-pointed at the reversed chain
+    pub fn reverse(&mut self) {
+        let mut reversed: Link<T> = None;
+        let mut cur_link = self.head.take();
+        while let Some(mut node) = cur_link {
+            cur_link = node.next.take();
+            node.next = reversed;
+            reversed = Some(node);
+        }
+        self.head = reversed;
+    }
+
+    /// Moves every element of `other` onto the end of this list, leaving `other` empty
+    /// # Remarks
+    /// * Walking to our tail is O(n), but `other`'s nodes are relinked as-is without cloning any elements
+    /// * If `other` is empty this is a no-op; if `self` is empty it simply takes `other`'s head
+    pub fn append(&mut self, other: &mut List<T>) {
+        if other.head.is_some() {
+            *self.last_link() = other.head.take();
+            self.len += other.len;
+            other.len = 0;
+        }
+    }
+
+    /// Splits the list in two at `at`, returning everything from index `at` onwards
+    /// # Remarks
+    /// * `self` keeps the first `at` elements; the remaining nodes are detached as-is into the returned list
+    /// * `at == 0` moves the whole list out, leaving `self` empty
+    /// * `at >= len` returns an empty list and leaves `self` untouched
+    pub fn split_off(&mut self, at: usize) -> List<T> {
+        let split_len = self.len.saturating_sub(at);
+        let head = self.link_at(at).take();
+        self.len -= split_len;
+        List { head, len: split_len }
+    }
+
+    /// Inserts all of `other`'s nodes before the node currently at index `at`
+    /// # Remarks
+    /// * `at == 0` prepends `other`; `at >= len` appends it
+    /// * The list is cut at `at` with [List::split_off] and stitched back together with [List::append], so nodes are only relinked, never cloned
+    pub fn splice(&mut self, at: usize, mut other: List<T>) {
+        let mut back = self.split_off(at);
+        self.append(&mut other);
+        self.append(&mut back);
+    }
+
+    /// Removes up to the first `n` elements from the head and returns them as a new list
+    /// # Remarks
+    /// * The mirror image of [List::split_off]: the front is returned and `self` keeps the back
+    /// * `n == 0` returns an empty list; `n >= len` moves the whole list out, leaving `self` empty
+    pub fn take(&mut self, n: usize) -> List<T> {
+        let rest = self.split_off(n);
+        std::mem::replace(self, rest)
+    }
+
+    /// Moves the first `n` elements to the end of the list, so the element at index `n` becomes the head
+    /// # Remarks
+    /// * `n` is taken modulo the length, so rotating by `0` or any multiple of the length does nothing
+    /// * The front is detached with [List::take] and relinked after the tail with [List::append]; no element is cloned
+    pub fn rotate_left(&mut self, n: usize) {
+        let n = match self.len {
+            0 => return,
+            len => n % len,
+        };
+        if n == 0 {
+            return;
+        }
+        let mut front = self.take(n);
+        self.append(&mut front);
+    }
+
+    /// Splits the list at the first element matching `pred`, returning that element and everything after it
+    /// # Returns
+    /// * [Some] - The detached remainder, starting with the first matching element
+    /// * [None] - If no element matches, in which case `self` is left untouched
+    pub fn split_when<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Option<List<T>> {
+        let at = self.elems().position(&mut pred)?;
+        Some(self.split_off(at))
+    }
+
+    /// Keeps the first `len` elements from the head and frees the rest
+    /// # Remarks
+    /// * `truncate(0)` empties the list; a `len` at or past the current length does nothing
+    pub fn truncate(&mut self, len: usize) {
+        // The detached nodes are freed when the returned list is dropped
+        drop(self.split_off(len));
+    }
+
+    /// Sorts the list in ascending order
+    /// # Remarks
+    /// * This is a merge sort, which needs no random access: the chain is split in half, each half is sorted, and the halves are merged back together
+    /// * Nodes are only relinked, never allocated, and no element is cloned or moved out of its node
+    /// * The sort is stable, so equal elements keep their relative order
+    /// * O(n log n) comparisons, with recursion only O(log n) deep
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the list with a comparator function, using the same stable merge sort as [List::sort]
+    pub fn sort_by<F: FnMut(&T, &T) -> std::cmp::Ordering>(&mut self, mut cmp: F) {
+        let head = self.head.take();
+        self.head = Self::merge_sort(head, self.len, &mut cmp);
+    }
+
+    /// Sorts the list by the key `key` extracts from each element, using the same stable merge sort as [List::sort]
+    /// # Remarks
+    /// * `key` is called on both elements of every comparison, so it's best kept cheap
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.sort_by(|a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Sorts the chain starting at `head`, which must hold exactly `len` nodes, and returns the new head
+    fn merge_sort<F: FnMut(&T, &T) -> std::cmp::Ordering>(mut head: Link<T>, len: usize, cmp: &mut F) -> Link<T> {
+        if len <= 1 {
+            return head;
+        }
+
+        let mid = len / 2;
+        let mut cur_link = &mut head;
+        for _ in 0..mid {
+            // `mid < len`, so the first `mid` links all hold a node
+            cur_link = &mut cur_link.as_mut().unwrap().next;
+        }
+        let back = cur_link.take();
+
+        let front = Self::merge_sort(head, mid, cmp);
+        let back = Self::merge_sort(back, len - mid, cmp);
+        Self::merge(front, back, cmp)
+    }
+
+    /// Merges two sorted chains into one by relinking their nodes
+    /// # Remarks
+    /// * On ties the node from `front` goes first, which is what keeps the sort stable
+    fn merge<F: FnMut(&T, &T) -> std::cmp::Ordering>(mut front: Link<T>, mut back: Link<T>, cmp: &mut F) -> Link<T> {
+        let mut merged = None;
+        let mut tail = &mut merged;
+        while let (Some(a), Some(b)) = (&front, &back) {
+            let from_front = cmp(&a.elem, &b.elem) != std::cmp::Ordering::Greater;
+            let source = if from_front { &mut front } else { &mut back };
+            let mut node = source.take().unwrap();
+            *source = node.next.take();
+            tail = &mut tail.insert(node).next;
+        }
+        // At most one of the chains still has nodes, and they're already in order
+        *tail = front.or(back);
+        merged
+    }
+
+    /// Gets the link pointing at the node at `index` (`head` for index `0`)
+    /// # Remarks
+    /// * If `index` is past the end of the list, this is the empty `next` link of the last node
+    fn link_at(&mut self, index: usize) -> &mut Link<T> {
+        let mut cur_link = &mut self.head;
+        for _ in 0..index {
+            match cur_link {
+                Some(node) => cur_link = &mut node.next,
+                None => break,
+            }
+        }
+        cur_link
+    }
+
+    /// Gets the empty `next` link at the end of the list (or `head` if the list is empty)
+    /// # Remarks
+    /// * We don't keep a pointer to the tail, so this walks the whole list and is O(n)
+    fn last_link(&mut self) -> &mut Link<T> {
+        let mut cur_link = &mut self.head;
+        while let Some(node) = cur_link {
+            cur_link = &mut node.next;
+        }
+        cur_link
+    }
+
     /// Consume the list and return an iterator
     pub fn into_iterator(self) -> IntoIterator<T> {
         IntoIterator(self)
     }
 
+    /// Moves every element out of the list, from head to tail, while keeping the list itself around
+    /// # Remarks
+    /// * Dropping the [Drain] before it's exhausted frees any remaining nodes, so the list is always empty afterwards
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain { list: self }
+    }
+
     // We declare a fresh lifetime here for the *exact* borrow that
     // creates the iter. Now &self needs to be valid as long as the
     // Iter is around.
@@ -86,12 +546,40 @@ impl<T> List<T> {
     /// * we are moving the boxed node into map, which means it would be dropped after the map call
     /// * that means we'd have a dangling reference
     /// * to fix this, we need to borrow the node, and return a reference to it
+    /// * to make the iterator double-ended, a reference to every node is collected into a [Vec] here, so creating it is O(n) in time and memory
+    /// * methods on [List] that only walk forwards use the private, lazy `elems` walk instead, so they don't pay for that buffer
     pub fn iterator(&self) -> Iter<T> {
-        Iter { next: self.head.as_deref() }
+        let mut nodes = Vec::with_capacity(self.len);
+        let mut cur_node = self.head.as_deref();
+        while let Some(node) = cur_node {
+            nodes.push(node);
+            cur_node = node.next.as_deref();
+        }
+        Iter { nodes: nodes.into_iter() }
+    }
+
+    /// Lazily walks the elements from head to tail, for the methods that only need to go forwards
+    /// # Remarks
+    /// * Unlike [List::iterator] nothing is buffered, so this never allocates and stops as soon as the caller does
+    fn elems(&self) -> impl Iterator<Item = &T> {
+        std::iter::successors(self.head.as_deref(), |node| node.next.as_deref()).map(|node| &node.elem)
+    }
+
+    /// Walks the list from the head in chunks of `size` references, like `slice::chunks`
+    /// # Remarks
+    /// * Each chunk is buffered into a fresh [Vec]; the last chunk is shorter if `size` doesn't divide the length
+    /// * Panics if `size` is `0`
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = Vec<&T>> {
+        assert!(size != 0, "chunk size must be non-zero");
+        let mut iter = self.elems();
+        std::iter::from_fn(move || {
+            let chunk: Vec<&T> = iter.by_ref().take(size).collect();
+            if chunk.is_empty() { None } else { Some(chunk) }
+        })
     }
 
     pub fn iterator_mutable(&mut self) -> IteratorMutable<'_, T> {
-        IteratorMutable { next: self.head.as_deref_mut() }
+        IteratorMutable { next: self.head.as_deref_mut(), len: self.len }
     }
 }
 
@@ -104,35 +592,203 @@ impl<T> Drop for List<T> {
     }
 }
 
+/// Clones every element into a fresh chain of nodes, preserving the order
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        self.map(T::clone)
+    }
+}
+
+/// Compares two lists element by element, from head to tail
+/// # Remarks
+/// * Lists of differing lengths are never equal
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.elems().eq(other.elems())
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+/// Hashes the length followed by each element from head to tail, so equal lists always hash equally
+impl<T: std::hash::Hash> std::hash::Hash for List<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for elem in self.elems() {
+            elem.hash(state);
+        }
+    }
+}
+
+/// Formats the list from head to tail, e.g. `[3, 2, 1]`
+impl<T: std::fmt::Display> std::fmt::Display for List<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, elem) in self.elems().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", elem)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Formats the list the same way as a [Vec], from head to tail
+impl<T: std::fmt::Debug> std::fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.elems()).finish()
+    }
+}
+
+/// Allows reading elements with `list[index]`, counting from the head
+/// # Remarks
+/// * Each access walks `index` nodes from the head, so it's O(n) rather than O(1) like a [Vec]
+/// * Panics if `index` is out of range
+impl<T> std::ops::Index<usize> for List<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(elem) => elem,
+            None => panic!("index out of bounds: the len is {} but the index is {}", self.len(), index),
+        }
+    }
+}
+
+/// Builds a list from a [Vec] with [List::from_vec], so `v[0]` ends up at the head
+impl<T> From<Vec<T>> for List<T> {
+    fn from(v: Vec<T>) -> Self {
+        List::from_vec(v)
+    }
+}
+
+/// Builds a list by cloning each element of a slice, so `slice[0]` ends up at the head
+/// # Remarks
+/// * Like [List::from_vec], the slice is pushed back to front to keep its order
+impl<T: Clone> From<&[T]> for List<T> {
+    fn from(slice: &[T]) -> Self {
+        slice.iter().rev().cloned().collect()
+    }
+}
+
+/// Builds a list by pushing each item of the iterator onto the head
+/// # Remarks
+/// * Pushing reverses the order, so `vec![1, 2, 3].into_iter().collect::<List<_>>()` has `3` at the head
+/// * `IntoIterator` is shadowed by our own [IntoIterator] struct, so the trait is referred to by its full path
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: std::iter::IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
+/// Pushes each item of the iterator onto the head of the list
+/// # Remarks
+/// * This prepends, so the last item extended ends up at the head
+impl<T> Extend<T> for List<T> {
+    fn extend<I: std::iter::IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+/// Lets a list be consumed by a `for` loop, delegating to [List::into_iterator]
+impl<T> std::iter::IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iterator()
+    }
+}
+
+/// Lets a borrowed list be walked by a `for` loop, delegating to [List::iterator]
+impl<'a, T> std::iter::IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iterator()
+    }
+}
+
 impl<T> Iterator for IntoIterator<T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
         // access fields of a tuple struct numerically
         self.0.pop()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
 }
 
+impl<T> ExactSizeIterator for IntoIterator<T> {}
+
+// Popping an empty list keeps returning `None`, so every iterator here is fused
+impl<T> std::iter::FusedIterator for IntoIterator<T> {}
+
 // We *do* have a lifetime here, because Iter has one that we need to define
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.next.map(|node| {
-            self.next = node.next.as_deref();
-            &node.elem
-        })
+        self.nodes.next().map(|node| &node.elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.nodes.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nodes.next_back().map(|node| &node.elem)
     }
 }
 
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> std::iter::FusedIterator for Iter<'_, T> {}
+
 impl<'a, T> Iterator for IteratorMutable<'a, T> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.next.take().map(|node| {
             self.next = node.next.as_deref_mut();
+            self.len -= 1;
             &mut node.elem
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IteratorMutable<'_, T> {}
+
+impl<T> std::iter::FusedIterator for IteratorMutable<'_, T> {}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop()
+    }
+}
+
+impl<T> std::iter::FusedIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        while self.list.pop().is_some() {}
+    }
 }
 
 #[cfg(test)]
@@ -152,34 +808,104 @@ mod test {
     }
     
     #[test]
-    fn pop_empty_list() {
-        let mut list: List<i32> = List::new();
-        assert_eq!(list.pop(), None);
+    fn push_back() {
+        let mut list = List::new();
+        list.push_back(1);
+        assert_eq!(list.peek(), Some(&1));
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(list.into_iterator().collect::<Vec<_>>(), vec![1, 2, 3]);
     }
-    
+
     #[test]
-    fn pop_list() {
+    fn push_back_and_push() {
         let mut list = List::new();
+        list.push_back(2);
         list.push(1);
-        list.push(2);
-        list.push(3);
-        assert_eq!(list.pop(), Some(3));
+        list.push_back(3);
+        assert_eq!(list.pop(), Some(1));
         assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), None);
     }
 
     #[test]
-    fn peek_empty_list() {
-        let list: List<i32> = List::new();
-        assert_eq!(list.peek(), None);
+    fn from_vec() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.get(2), Some(&3));
+        assert!(List::<i32>::from_vec(Vec::new()).is_empty());
     }
-    
+
     #[test]
-    fn peek_list() {
+    fn into_vec() {
         let mut list = List::new();
-        list.push(1);
-        list.push(2);
-        list.push(3);
-        assert_eq!(list.peek(), Some(&3));
+        list.push(1); list.push(2); list.push(3);
+        assert_eq!(list.into_vec(), vec![3, 2, 1]);
+        assert_eq!(List::<i32>::new().into_vec(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn vec_round_trip() {
+        let v = vec![4, 8, 15, 16, 23, 42];
+        assert_eq!(List::from_vec(v.clone()).into_vec(), v);
+    }
+
+    #[test]
+    fn pop_empty_list() {
+        let mut list: List<i32> = List::new();
+        assert_eq!(list.pop(), None);
+    }
+    
+    #[test]
+    fn pop_list() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(2));
+    }
+
+    #[test]
+    fn pop_back_empty_list() {
+        let mut list: List<i32> = List::new();
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn pop_back_single_element_list() {
+        let mut list = List::new();
+        list.push(1);
+        assert_eq!(list.pop_back(), Some(1));
+        assert!(list.is_empty());
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn pop_back_list() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        list.push_back(4);
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn peek_empty_list() {
+        let list: List<i32> = List::new();
+        assert_eq!(list.peek(), None);
+    }
+    
+    #[test]
+    fn peek_list() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.peek(), Some(&3));
         assert_eq!(list.peek_mut(), Some(&mut 3));
     }
     
@@ -199,6 +925,126 @@ mod test {
         assert_eq!(list.peek_mut(), Some(&mut 3));
     }
     
+    #[test]
+    fn len_empty_list() {
+        let list: List<i32> = List::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn len_single_element_list() {
+        let mut list = List::new();
+        list.push(1);
+        assert_eq!(list.len(), 1);
+        assert!(!list.is_empty());
+    }
+
+    #[test]
+    fn len_list() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.len(), 3);
+        list.pop();
+        assert_eq!(list.len(), 2);
+        assert!(!list.is_empty());
+    }
+
+    #[test]
+    fn count_matching() {
+        let list: List<i32> = vec![1, 2, 3, 4, 6, 7, -2].into_iter().collect();
+        assert_eq!(list.count_matching(|elem| elem % 2 == 0), 4);
+        assert_eq!(list.count_matching(|elem| *elem > 100), 0);
+    }
+
+    #[test]
+    fn map() {
+        let list: List<i32> = (1..=3).rev().collect();
+        let mapped = list.map(|elem| format!("#{}", elem));
+        assert_eq!(mapped.into_iterator().collect::<Vec<_>>(), vec!["#1", "#2", "#3"]);
+        assert_eq!(list.peek(), Some(&1));
+    }
+
+    #[test]
+    fn retain_head() {
+        let mut list: List<i32> = (1..=4).rev().collect();
+        list.retain(|elem| *elem != 1);
+        assert_eq!(list, (2..=4).rev().collect());
+    }
+
+    #[test]
+    fn retain_middle() {
+        let mut list: List<i32> = (1..=4).rev().collect();
+        list.retain(|elem| *elem % 2 == 1);
+        assert_eq!(list, vec![3, 1].into_iter().collect());
+    }
+
+    #[test]
+    fn retain_tail() {
+        let mut list: List<i32> = (1..=4).rev().collect();
+        list.retain(|elem| *elem != 4);
+        assert_eq!(list, (1..=3).rev().collect());
+        list.push_back(5);
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn retain_nothing() {
+        let mut list: List<i32> = (1..=4).collect();
+        list.retain(|_| false);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn len_after_relinking() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4]);
+        list.push_back(5);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.pop_back(), Some(5));
+        assert_eq!(list.len(), 4);
+
+        let mut back = list.split_off(1);
+        assert_eq!((list.len(), back.len()), (1, 3));
+        assert_eq!(list.split_off(5).len(), 0);
+
+        list.append(&mut back);
+        assert_eq!((list.len(), back.len()), (4, 0));
+
+        list.retain(|elem| elem % 2 == 0);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.map(|elem| elem * 2).len(), 2);
+        list.truncate(0);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn size_hint() {
+        let mut list = List::new();
+        list.push(1); list.push(2); list.push(3);
+
+        let mut iter = list.iterator();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        iter.next(); iter.next();
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+
+        let mut iter = list.iterator_mutable();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.len(), 2);
+
+        let mut iter = list.into_iterator();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.len(), 2);
+    }
+
     #[test]
     fn into_iterator() {
         let mut list = List::new();
@@ -223,6 +1069,22 @@ mod test {
         assert_eq!(iter.next(), Some(&1));
     }
 
+    #[test]
+    fn iterator_double_ended() {
+        let list = List::from_vec(vec![1, 2, 3, 4]);
+
+        let mut iter = list.iterator();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(list.iterator().rev().collect::<Vec<_>>(), vec![&4, &3, &2, &1]);
+    }
+
     #[test]
     fn iterator_mutable() {
         let mut list = List::new();
@@ -233,5 +1095,744 @@ mod test {
         assert_eq!(iter.next(), Some(&mut 2));
         assert_eq!(iter.next(), Some(&mut 1));
     }
-}
 
+    #[test]
+    fn for_loop_by_value() {
+        let mut list = List::new();
+        list.push(1); list.push(2); list.push(3);
+
+        let mut elems = Vec::new();
+        for elem in list {
+            elems.push(elem);
+        }
+        assert_eq!(elems, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn for_loop_by_reference() {
+        let mut list = List::new();
+        list.push(1); list.push(2); list.push(3);
+
+        let mut elems = Vec::new();
+        for elem in &list {
+            elems.push(*elem);
+        }
+        assert_eq!(elems, vec![3, 2, 1]);
+        assert_eq!(list.peek(), Some(&3));
+    }
+
+    #[test]
+    fn clone_empty_list() {
+        let list: List<i32> = List::new();
+        assert!(list.clone().is_empty());
+    }
+
+    #[test]
+    fn clone_list() {
+        let mut list = List::new();
+        list.push(1); list.push(2); list.push(3);
+
+        let mut cloned = list.clone();
+        assert_eq!(cloned.pop(), Some(3));
+        assert_eq!(cloned.pop(), Some(2));
+        if let Some(value) = cloned.peek_mut() { *value = 42 }
+
+        let mut iter = list.iterator();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn eq() {
+        let mut list = List::new();
+        list.push(1); list.push(2); list.push(3);
+        let other: List<i32> = (1..=3).collect();
+        assert_eq!(list, other);
+        assert_eq!(List::<i32>::new(), List::new());
+    }
+
+    #[test]
+    fn ne_different_lengths() {
+        let mut list = List::new();
+        list.push(1); list.push(2); list.push(3);
+        let mut other = List::new();
+        other.push(2); other.push(3);
+        assert_ne!(list, other);
+        assert_ne!(other, list);
+    }
+
+    #[test]
+    fn ne_different_contents() {
+        let mut list = List::new();
+        list.push(1); list.push(2); list.push(3);
+        let mut other = List::new();
+        other.push(1); other.push(5); other.push(3);
+        assert_ne!(list, other);
+    }
+
+    #[test]
+    fn reverse_empty_list() {
+        let mut list: List<i32> = List::new();
+        list.reverse();
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn reverse_single_element_list() {
+        let mut list = List::new();
+        list.push(1);
+        list.reverse();
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn reverse_list() {
+        let mut list = List::new();
+        list.push(1); list.push(2); list.push(3);
+        list.reverse();
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn append() {
+        let mut list = List::new();
+        list.push(2); list.push(1);
+        let mut other = List::new();
+        other.push(4); other.push(3);
+
+        list.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn append_to_empty_list() {
+        let mut list = List::new();
+        let mut other = List::new();
+        other.push(2); other.push(1);
+
+        list.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn append_empty_list() {
+        let mut list = List::new();
+        list.push(1);
+        let mut other = List::new();
+
+        list.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn get() {
+        let mut list = List::new();
+        list.push(1); list.push(2); list.push(3);
+        assert_eq!(list.get(0), list.peek());
+        assert_eq!(list.get(0), Some(&3));
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), Some(&1));
+        assert_eq!(list.get(3), None);
+    }
+
+    #[test]
+    fn index() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        assert_eq!(list[0], 1);
+        assert_eq!(list[1], 2);
+        assert_eq!(list[2], 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn index_out_of_range() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        let _ = list[3];
+    }
+
+    #[test]
+    fn contains() {
+        let mut list = List::new();
+        list.push(1); list.push(2); list.push(3);
+        assert!(list.contains(&1));
+        assert!(list.contains(&3));
+        assert!(!list.contains(&4));
+    }
+
+    #[test]
+    fn contains_empty_list() {
+        let list: List<i32> = List::new();
+        assert!(!list.contains(&1));
+    }
+
+    #[test]
+    fn split_off() {
+        let mut list: List<i32> = (1..=4).rev().collect();
+        let back = list.split_off(2);
+        assert_eq!(list, (1..=2).rev().collect());
+        assert_eq!(back, (3..=4).rev().collect());
+    }
+
+    #[test]
+    fn split_off_at_zero() {
+        let mut list: List<i32> = (1..=3).rev().collect();
+        let back = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(back, (1..=3).rev().collect());
+    }
+
+    #[test]
+    fn split_off_at_len() {
+        let mut list: List<i32> = (1..=3).rev().collect();
+        assert!(list.split_off(3).is_empty());
+        assert!(list.split_off(10).is_empty());
+        assert_eq!(list, (1..=3).rev().collect());
+
+        let back = list.split_off(2);
+        assert_eq!(list.len(), 2);
+        assert_eq!(back.peek(), Some(&3));
+        assert_eq!(back.len(), 1);
+    }
+
+    #[test]
+    fn drain() {
+        let mut list: List<i32> = (1..=3).rev().collect();
+        assert_eq!(list.drain().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert!(list.is_empty());
+
+        list.push(4);
+        assert_eq!(list.pop(), Some(4));
+    }
+
+    #[test]
+    fn drain_partially() {
+        let mut list: List<i32> = (1..=4).rev().collect();
+        let mut drain = list.drain();
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.next(), Some(2));
+        drop(drain);
+        assert!(list.is_empty());
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(List::from_vec(vec![1, 2, 3]));
+        set.insert(List::from_vec(vec![1, 2, 3]));
+        assert_eq!(set.len(), 1);
+
+        set.insert(List::from_vec(vec![3, 2, 1]));
+        set.insert(List::new());
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn split_when() {
+        let mut list = List::from_vec(vec![1, 2, 10, 3, 20]);
+        let back = list.split_when(|elem| *elem >= 10).unwrap();
+        assert_eq!(list.into_vec(), vec![1, 2]);
+        assert_eq!(back.into_vec(), vec![10, 3, 20]);
+    }
+
+    #[test]
+    fn split_when_head_matches() {
+        let mut list = List::from_vec(vec![1, 2]);
+        let back = list.split_when(|_| true).unwrap();
+        assert!(list.is_empty());
+        assert_eq!(back.len(), 2);
+    }
+
+    #[test]
+    fn split_when_nothing_matches() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        assert!(list.split_when(|elem| *elem > 3).is_none());
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn truncate() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4]);
+        list.truncate(2);
+        assert_eq!(list.into_vec(), vec![1, 2]);
+    }
+
+    #[test]
+    fn truncate_to_zero() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        list.truncate(0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn truncate_to_len_or_more() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        list.truncate(3);
+        assert_eq!(list, List::from_vec(vec![1, 2, 3]));
+        list.truncate(10);
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn display() {
+        let mut list = List::new();
+        assert_eq!(format!("{}", list), "[]");
+
+        list.push(1); list.push(2); list.push(3);
+        assert_eq!(format!("{}", list), "[3, 2, 1]");
+    }
+
+    #[test]
+    fn debug() {
+        let mut list = List::new();
+        assert_eq!(format!("{:?}", list), "[]");
+
+        list.push(1); list.push(2); list.push(3);
+        assert_eq!(format!("{:?}", list), "[3, 2, 1]");
+    }
+
+    #[test]
+    fn from_iterator() {
+        let mut list: List<i32> = (1..=3).collect();
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn extend() {
+        let mut list = List::new();
+        list.push(1);
+        list.extend(vec![2, 3]);
+        assert_eq!(list.peek(), Some(&3));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(1));
+    }
+
+    #[test]
+    fn fold() {
+        let list = List::from_vec(vec![1, 2, 3, 4]);
+        assert_eq!(list.fold(0, |sum, elem| sum + elem), 10);
+        assert_eq!(list.fold(String::new(), |acc, elem| acc + &elem.to_string()), "1234");
+        assert_eq!(List::<i32>::new().fold(7, |sum, elem| sum + elem), 7);
+    }
+
+    #[test]
+    fn for_each() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        let mut seen = Vec::new();
+        list.for_each(|elem| seen.push(*elem * 10));
+        assert_eq!(seen, vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn take_none() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        let front = list.take(0);
+        assert!(front.is_empty());
+        assert_eq!(front.len(), 0);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn take_mid() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4, 5]);
+        let front = list.take(2);
+        assert_eq!(front.len(), 2);
+        assert_eq!(list.len(), 3);
+        assert_eq!(front.into_vec(), vec![1, 2]);
+        assert_eq!(list.into_vec(), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn take_oversized() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        let front = list.take(10);
+        assert_eq!(front.len(), 3);
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(front.into_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn concat() {
+        let list = List::concat(vec![
+            List::from_vec(vec![1, 2]),
+            List::new(),
+            List::from_vec(vec![3]),
+            List::from_vec(vec![4, 5, 6]),
+        ]);
+        assert_eq!(list.len(), 6);
+        assert_eq!(list.into_vec(), vec![1, 2, 3, 4, 5, 6]);
+
+        let empty: List<i32> = List::concat(Vec::new());
+        assert!(empty.is_empty());
+        assert_eq!(empty.len(), 0);
+    }
+
+    #[test]
+    fn sort_reversed() {
+        let mut list = List::from_vec(vec![5, 4, 3, 2, 1]);
+        list.sort();
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.into_vec(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn sort_duplicates() {
+        let mut list = List::from_vec(vec![3, 1, 2, 3, 1, 2, 1]);
+        list.sort();
+        assert_eq!(list.len(), 7);
+        assert_eq!(list.into_vec(), vec![1, 1, 1, 2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn sort_already_sorted() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4]);
+        list.sort();
+        assert_eq!(list.into_vec(), vec![1, 2, 3, 4]);
+
+        let mut list: List<i32> = List::new();
+        list.sort();
+        assert!(list.is_empty());
+
+        let mut list = List::from_vec(vec![1]);
+        list.sort();
+        assert_eq!(list.into_vec(), vec![1]);
+    }
+
+    #[test]
+    fn sort_by_descending() {
+        let mut list = List::from_vec(vec![2, 5, 1, 4, 3]);
+        list.sort_by(|a, b| b.cmp(a));
+        assert_eq!(list.into_vec(), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn sort_by_key_field() {
+        #[derive(Debug, PartialEq)]
+        struct Person {
+            name: &'static str,
+            age: u32,
+        }
+
+        let mut list = List::from_vec(vec![
+            Person { name: "Ada", age: 36 },
+            Person { name: "Bob", age: 25 },
+            Person { name: "Cy", age: 36 },
+            Person { name: "Di", age: 19 },
+        ]);
+        list.sort_by_key(|person| person.age);
+
+        let names: Vec<&str> = list.iterator().map(|person| person.name).collect();
+        // Ada and Cy share an age, and the stable sort keeps them in their original order
+        assert_eq!(names, vec!["Di", "Bob", "Ada", "Cy"]);
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn dedup_runs() {
+        let mut list = List::from_vec(vec![1, 1, 2, 3, 3, 3, 1, 4, 4]);
+        list.dedup();
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.into_vec(), vec![1, 2, 3, 1, 4]);
+    }
+
+    #[test]
+    fn dedup_no_duplicates() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        list.dedup();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let mut list: List<i32> = List::new();
+        list.dedup();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn dedup_all_equal() {
+        let mut list = List::from_vec(vec![7, 7, 7, 7]);
+        list.dedup();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.into_vec(), vec![7]);
+    }
+
+    #[test]
+    fn chunks_even() {
+        let list = List::from_vec(vec![1, 2, 3, 4, 5, 6]);
+        let chunks: Vec<Vec<&i32>> = list.chunks(2).collect();
+        assert_eq!(chunks, vec![vec![&1, &2], vec![&3, &4], vec![&5, &6]]);
+    }
+
+    #[test]
+    fn chunks_uneven() {
+        let list = List::from_vec(vec![1, 2, 3, 4, 5]);
+        let chunks: Vec<Vec<&i32>> = list.chunks(3).collect();
+        assert_eq!(chunks, vec![vec![&1, &2, &3], vec![&4, &5]]);
+
+        let chunks: Vec<Vec<&i32>> = list.chunks(10).collect();
+        assert_eq!(chunks, vec![vec![&1, &2, &3, &4, &5]]);
+
+        assert_eq!(List::<i32>::new().chunks(2).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_zero_size() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        let _ = list.chunks(0);
+    }
+
+    #[test]
+    fn from_vec_trait() {
+        let list = List::from(vec![1, 2, 3]);
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let list: List<i32> = Vec::new().into();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn from_slice() {
+        let elems = [1, 2, 3];
+        let list = List::from(&elems[..]);
+        assert_eq!(list.peek(), Some(&elems[0]));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), elems.to_vec());
+    }
+
+    #[test]
+    fn splice_head() {
+        let mut list = List::from_vec(vec![3, 4]);
+        list.splice(0, List::from_vec(vec![1, 2]));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.into_vec(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn splice_middle() {
+        let mut list = List::from_vec(vec![1, 4]);
+        list.splice(1, List::from_vec(vec![2, 3]));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.into_vec(), vec![1, 2, 3, 4]);
+
+        let mut list = List::from_vec(vec![1, 2]);
+        list.splice(1, List::new());
+        assert_eq!(list.into_vec(), vec![1, 2]);
+    }
+
+    #[test]
+    fn splice_tail() {
+        let mut list = List::from_vec(vec![1, 2]);
+        list.splice(2, List::from_vec(vec![3, 4]));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.into_vec(), vec![1, 2, 3, 4]);
+
+        let mut list = List::new();
+        list.splice(0, List::from_vec(vec![1]));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.into_vec(), vec![1]);
+    }
+
+    #[test]
+    fn remove_head() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        assert_eq!(list.remove(0), Some(1));
+        assert_eq!(list.peek(), Some(&2));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.into_vec(), vec![2, 3]);
+    }
+
+    #[test]
+    fn remove_middle_and_tail() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4]);
+        assert_eq!(list.remove(1), Some(2));
+        assert_eq!(list.remove(2), Some(4));
+        assert_eq!(list.len(), 2);
+        list.push_back(5);
+        assert_eq!(list.into_vec(), vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn remove_out_of_range() {
+        let mut list = List::from_vec(vec![1, 2]);
+        assert_eq!(list.remove(2), None);
+        assert_eq!(list.remove(10), None);
+        assert_eq!(list.len(), 2);
+        assert_eq!(List::<i32>::new().remove(0), None);
+    }
+
+    #[test]
+    fn insert_head() {
+        let mut list = List::from_vec(vec![2, 3]);
+        list.insert(0, 1);
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let mut list = List::new();
+        list.insert(0, 1);
+        assert_eq!(list.into_vec(), vec![1]);
+    }
+
+    #[test]
+    fn insert_middle_and_tail() {
+        let mut list = List::from_vec(vec![1, 3]);
+        list.insert(1, 2);
+        list.insert(3, 4);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.into_vec(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 3) should be <= len (is 2)")]
+    fn insert_out_of_range() {
+        let mut list = List::from_vec(vec![1, 2]);
+        list.insert(3, 3);
+    }
+
+    #[test]
+    fn fused() {
+        let mut list = List::from_vec(vec![1, 2]);
+
+        let mut iter = list.iterator().fuse();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = list.iterator_mutable().fuse();
+        iter.next(); iter.next();
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = list.into_iterator().fuse();
+        iter.next(); iter.next();
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn last() {
+        let mut list = List::new();
+        assert_eq!(list.last(), None);
+
+        list.push(3); list.push(2); list.push(1);
+        assert_eq!(list.last(), Some(&3));
+        assert_eq!(list.last(), list.get(list.len() - 1));
+    }
+
+    #[test]
+    fn last_mut() {
+        let mut list: List<i32> = List::new();
+        assert_eq!(list.last_mut(), None);
+
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        if let Some(elem) = list.last_mut() {
+            *elem *= 10;
+        }
+        assert_eq!(list.last(), Some(&30));
+        assert_eq!(list.into_vec(), vec![1, 2, 30]);
+    }
+
+    #[test]
+    fn swap_head_and_tail() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4]);
+        list.swap(0, 3);
+        assert_eq!(list, List::from_vec(vec![4, 2, 3, 1]));
+        list.swap(3, 0);
+        assert_eq!(list.into_vec(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn swap_adjacent() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4]);
+        list.swap(1, 2);
+        assert_eq!(list, List::from_vec(vec![1, 3, 2, 4]));
+        list.swap(2, 2);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.into_vec(), vec![1, 3, 2, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 5")]
+    fn swap_out_of_range() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        list.swap(0, 5);
+    }
+
+    #[test]
+    fn rotate_left_by_one() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4]);
+        list.rotate_left(1);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.into_vec(), vec![2, 3, 4, 1]);
+    }
+
+    #[test]
+    fn rotate_left_by_len() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        list.rotate_left(0);
+        list.rotate_left(3);
+        list.rotate_left(6);
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let mut list: List<i32> = List::new();
+        list.rotate_left(2);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn rotate_left_by_more_than_len() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        list.rotate_left(5);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn partition() {
+        let list = List::from_vec(vec![1, 2, 3, 4, 5, 6, 7]);
+        let (evens, odds) = list.partition(|elem| elem % 2 == 0);
+        assert_eq!(evens.len(), 3);
+        assert_eq!(odds.len(), 4);
+        assert_eq!(evens.into_vec(), vec![2, 4, 6]);
+        assert_eq!(odds.into_vec(), vec![1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn partition_one_sided() {
+        let (all, none) = List::from_vec(vec![2, 4]).partition(|elem| elem % 2 == 0);
+        assert_eq!(all.into_vec(), vec![2, 4]);
+        assert!(none.is_empty());
+        assert_eq!(none.len(), 0);
+
+        let (matched, unmatched) = List::<i32>::new().partition(|_| true);
+        assert!(matched.is_empty());
+        assert!(unmatched.is_empty());
+    }
+}
@@ -40,6 +40,8 @@
 //! - This means we can't ever really get data out of one of our lists, nor can we mutate them
 //! - This is a problem because we need to be able to mutate the tails of our lists. IVF-w1ll-w0rk-!#%&
 
+use std::fmt;
+use std::iter::FusedIterator;
 use std::rc::Rc;
 
 /// An [`alias`](https://doc.rust-lang.org/book/ch19-02-advanced-traits.html#using-type-aliases-to-reduce-repetition-with-the-result-type-alias-pattern) for a singly-linked list node.
@@ -84,6 +86,21 @@ impl<T> SinglyLinkedList<T> {
         }
     }
     
+    /// Adds every element of `items` to the front of the list, one after another.
+    /// # Arguments
+    /// * `items` - The elements to add to the list.
+    /// # Returns
+    /// A new list with all of the elements added to the front.
+    /// # Remarks
+    /// - This is the same as folding [`SinglyLinkedList::prepend`] over `items`, so `prepend_all([1, 2, 3])` equals `prepend(1).prepend(2).prepend(3)`.
+    /// - The last item of `items` becomes the new head and the first item ends up deepest, directly in front of the old head: `3 -> 2 -> 1 -> (old list)`.
+    /// - The old list is shared, not copied, exactly as with a single `prepend`.
+    pub fn prepend_all<I: IntoIterator<Item = T>>(&self, items: I) -> SinglyLinkedList<T> {
+        items
+            .into_iter()
+            .fold(self.clone(), |list, element| list.prepend(element))
+    }
+
     /// Removes the first element from the list and returns it.
     /// # Returns
     /// A tuple containing the first element of the list and a new list with the first element removed.
@@ -98,6 +115,36 @@ impl<T> SinglyLinkedList<T> {
         }
     }
 
+    /// Returns the list starting `n` elements in, sharing its nodes with this list.
+    /// # Arguments
+    /// * `n` - The number of elements to skip.
+    /// # Returns
+    /// A new list whose head is the `n`th node of this list, or an empty list if `n` is past the end.
+    /// # Remarks
+    /// - This is like calling [`SinglyLinkedList::tail`] `n` times, but only the final `Rc` is cloned.
+    /// - The walk is O(n), but no elements are cloned.
+    /// - `nth_tail(0)` is the same as cloning the whole list.
+    pub fn nth_tail(&self, n: usize) -> SinglyLinkedList<T> {
+        let mut cur_node = self.head.as_ref();
+        for _ in 0..n {
+            cur_node = cur_node.and_then(|node| node.next.as_ref());
+        }
+        SinglyLinkedList { head: cur_node.cloned() }
+    }
+
+    /// Builds a new list by applying `f` to each element.
+    /// # Arguments
+    /// * `f` - The function to apply to each element.
+    /// # Returns
+    /// A new list of the results, in the same head-to-tail order.
+    /// # Remarks
+    /// - The new list has a different element type, so it can't share any nodes with this one: every node is freshly allocated.
+    /// - Lists that shared a tail before mapping won't share one after; map the shared tail once and `prepend` onto it to keep that sharing.
+    /// - This is built with [`FromIterator`], which buffers the results in a `Vec` first.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> SinglyLinkedList<U> {
+        self.iterator().map(f).collect()
+    }
+
     /// Returns a reference to the first element of the list.
     /// # Returns
     /// An `Option` containing a reference to the first element of the list.
@@ -112,10 +159,72 @@ impl<T> SinglyLinkedList<T> {
         self.head.as_ref().map(|node| &node.element)
     }
 
+    /// Returns a reference to the last element of the list.
+    /// # Returns
+    /// An `Option` containing a reference to the element of the final node, or `None` if the list is empty.
+    /// # Remarks
+    /// - The chain is walked with plain references via `as_deref`, so no `Rc` is cloned.
+    /// - The last node is usually shared by every list built on top of it.
+    pub fn last(&self) -> Option<&T> {
+        let mut cur_node = self.head.as_deref()?;
+        while let Some(next) = cur_node.next.as_deref() {
+            cur_node = next;
+        }
+        Some(&cur_node.element)
+    }
+
+    /// Returns the number of elements in the list.
+    /// # Remarks
+    /// - The chain is walked with plain references via `as_deref`, so no `Rc` is cloned and no reference count changes.
+    /// - Because tails are shared, this counts every node reachable from this list's head, including nodes shared with other lists.
+    pub fn len(&self) -> usize {
+        let mut count = 0;
+        let mut cur_node = self.head.as_deref();
+        while let Some(node) = cur_node {
+            count += 1;
+            cur_node = node.next.as_deref();
+        }
+        count
+    }
+
+    /// Returns `true` if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns `true` if both lists start at the very same node.
+    /// # Remarks
+    /// - The heads are compared with `Rc::ptr_eq`, so this checks structural sharing rather than equal elements.
+    /// - Lists that share a head share every node after it as well, e.g. `list.tail()` and `list.tail().prepend(x).tail()`.
+    /// - Two empty lists have no node to share, so this returns `false` for them.
+    pub fn shares_tail_with(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+
+    /// Returns the number of strong references to the head node, or `0` if the list is empty.
+    /// # Remarks
+    /// - This is `Rc::strong_count` of the head, which makes structural sharing visible.
+    /// - Every list whose head is this node counts once (e.g. via `clone`), and so does every node that has it as its `next` (e.g. via `prepend`).
+    pub fn head_strong_count(&self) -> usize {
+        self.head.as_ref().map_or(0, Rc::strong_count)
+    }
+
     /// Returns an iterator over the elements of the [`SinglyLinkedList`].
     pub fn iterator(&self) -> Iter<'_, T> {
         Iter { next: self.head.as_deref() }
     }
+
+    /// Returns an iterator over the elements of the [`SinglyLinkedList`], from tail to head.
+    /// # Remarks
+    /// - The nodes only point forwards, so a reference to every element is first buffered in a `Vec`, which is then walked backwards.
+    /// - Creating the iterator is therefore O(n) in both time and memory, even if only the first few items are used.
+    pub fn iter_rev(&self) -> impl Iterator<Item = &T> {
+        let elements: Vec<&T> = self.iterator().collect();
+        elements.into_iter().rev()
+    }
 }
 
 /// An iterator over a [`SinglyLinkedList`].
@@ -135,6 +244,108 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
+/// Implement the [`FusedIterator`] trait for the [`Iter`] struct.
+/// # Remarks
+/// - Once the last node has been visited, `next` stays `None`.
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// A consuming iterator over a [`SinglyLinkedList`].
+/// # Remarks
+/// - Nodes may be shared with other lists, so elements can't be moved out of them.
+/// - Instead, each element is cloned out and the iterator steps to the [`SinglyLinkedList::tail`], releasing its hold on the node it just visited.
+pub struct IntoIter<T>(SinglyLinkedList<T>);
+
+/// Implement the [`Iterator`] trait for the [`IntoIter`] struct.
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.0.head().cloned()?;
+        self.0 = self.0.tail();
+        Some(element)
+    }
+}
+
+/// Implement the [`FusedIterator`] trait for the [`IntoIter`] struct.
+/// # Remarks
+/// - The tail of an empty list is an empty list, so `next` stays `None`.
+impl<T: Clone> FusedIterator for IntoIter<T> {}
+
+/// Implement the [`IntoIterator`] trait for the [`SinglyLinkedList`] struct.
+/// # Remarks
+/// - This clones every element rather than moving it, because other lists may still own the same nodes.
+/// - Elements are yielded from head to tail.
+impl<T: Clone> IntoIterator for SinglyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+/// Implement the [`Clone`] trait for the [`SinglyLinkedList`] struct.
+/// # Remarks
+/// - Cloning is O(1): only the `head` `Rc` is cloned, which increments its reference count.
+/// - The clone structurally shares every node with the original, so no `T: Clone` bound is needed.
+impl<T> Clone for SinglyLinkedList<T> {
+    fn clone(&self) -> Self {
+        SinglyLinkedList { head: self.head.clone() }
+    }
+}
+
+/// Implement the [`PartialEq`] trait for the [`SinglyLinkedList`] struct.
+/// # Remarks
+/// - If both heads are the same `Rc`, the lists share every node and are equal without looking at a single element.
+/// - Otherwise the elements are compared one by one using [`SinglyLinkedList::iterator`].
+impl<T: PartialEq> PartialEq for SinglyLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) if Rc::ptr_eq(a, b) => true,
+            _ => self.iterator().eq(other.iterator()),
+        }
+    }
+}
+
+/// Implement the [`FromIterator`] trait for the [`SinglyLinkedList`] struct.
+/// # Remarks
+/// - We want the head of the list to be the first item of the iterator, so `(1..=3).collect()` reads `1 -> 2 -> 3`.
+/// - A persistent list can only grow at the front, so the items are collected into a `Vec` first and then prepended in reverse.
+impl<T> FromIterator<T> for SinglyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let elements: Vec<T> = iter.into_iter().collect();
+        elements
+            .into_iter()
+            .rev()
+            .fold(SinglyLinkedList::new(), |list, element| list.prepend(element))
+    }
+}
+
+/// Implement the [`Debug`](fmt::Debug) trait for the [`SinglyLinkedList`] struct.
+/// # Remarks
+/// - Formats the same way as a `Vec`, e.g. `[3, 2, 1]`.
+impl<T: fmt::Debug> fmt::Debug for SinglyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iterator()).finish()
+    }
+}
+
+/// Implement the [`Display`](fmt::Display) trait for the [`SinglyLinkedList`] struct.
+/// # Remarks
+/// - Formats the elements from head to tail separated by arrows, e.g. `3 -> 2 -> 1`.
+/// - An empty list formats as an empty string.
+impl<T: fmt::Display> fmt::Display for SinglyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, element) in self.iterator().enumerate() {
+            if i > 0 {
+                write!(f, " -> ")?;
+            }
+            write!(f, "{}", element)?;
+        }
+        Ok(())
+    }
+}
+
 impl<T> Drop for SinglyLinkedList<T> {
     /// Drops the [`SinglyLinkedList`] and all its elements.
     /// # Algorithm
@@ -190,4 +401,227 @@ mod test {
         assert_eq!(iter.next(), Some(&2));
         assert_eq!(iter.next(), Some(&1));
     }
+
+    #[test]
+    fn len() {
+        let list = SinglyLinkedList::new();
+        assert_eq!(list.len(), 0);
+
+        let list = list.prepend(1).prepend(2).prepend(3);
+        assert_eq!(list.len(), 3);
+
+        let tail = list.tail();
+        assert_eq!(tail.len(), 2);
+        // Taking the tail leaves the original list untouched
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(tail.tail().tail().len(), 0);
+        assert_eq!(tail.tail().tail().tail().len(), 0);
+    }
+
+    #[test]
+    fn clone() {
+        let list = SinglyLinkedList::new().prepend(1).prepend(2).prepend(3);
+        let cloned = list.clone();
+
+        // Both handles point at the very same head node
+        assert!(std::ptr::eq(list.head().unwrap(), cloned.head().unwrap()));
+
+        drop(list);
+        assert_eq!(cloned.head(), Some(&3));
+        assert_eq!(cloned.len(), 3);
+    }
+
+    #[test]
+    fn eq_shared() {
+        let list = SinglyLinkedList::new().prepend(1).prepend(2);
+        let shared = list.clone();
+        assert!(list == shared);
+        assert!(list.tail() == shared.tail());
+    }
+
+    #[test]
+    fn eq_distinct() {
+        let list = SinglyLinkedList::new().prepend(1).prepend(2);
+        let other = SinglyLinkedList::new().prepend(1).prepend(2);
+        assert!(list == other);
+        assert!(SinglyLinkedList::<i32>::new() == SinglyLinkedList::new());
+    }
+
+    #[test]
+    fn ne() {
+        let list = SinglyLinkedList::new().prepend(1).prepend(2);
+        assert!(list != SinglyLinkedList::new().prepend(1).prepend(3));
+        assert!(list != list.prepend(3));
+        assert!(list != list.tail());
+        assert!(list != SinglyLinkedList::new());
+    }
+
+    #[test]
+    fn debug() {
+        let list = SinglyLinkedList::new().prepend(1).prepend(2).prepend(3);
+        assert_eq!(format!("{:?}", list), "[3, 2, 1]");
+        assert_eq!(format!("{:?}", SinglyLinkedList::<i32>::new()), "[]");
+    }
+
+    #[test]
+    fn display() {
+        let list = SinglyLinkedList::new().prepend(1).prepend(2).prepend(3);
+        assert_eq!(format!("{}", list), "3 -> 2 -> 1");
+        assert_eq!(format!("{}", list.tail()), "2 -> 1");
+        assert_eq!(format!("{}", SinglyLinkedList::<i32>::new()), "");
+    }
+
+    #[test]
+    fn from_iterator() {
+        let list: SinglyLinkedList<i32> = (1..=3).collect();
+        assert_eq!(list.head(), Some(&1));
+        assert_eq!(list, SinglyLinkedList::new().prepend(3).prepend(2).prepend(1));
+
+        let list: SinglyLinkedList<i32> = std::iter::empty().collect();
+        assert_eq!(list.head(), None);
+    }
+
+    #[test]
+    fn nth_tail() {
+        let list: SinglyLinkedList<i32> = (1..=4).collect();
+
+        let whole = list.nth_tail(0);
+        assert!(std::ptr::eq(whole.head().unwrap(), list.head().unwrap()));
+
+        let tail = list.nth_tail(2);
+        assert_eq!(tail.head(), Some(&3));
+        assert_eq!(tail.len(), 2);
+        // The returned list shares nodes with the original rather than copying them
+        assert!(std::ptr::eq(tail.head().unwrap(), list.tail().tail().head().unwrap()));
+
+        assert_eq!(list.nth_tail(4).head(), None);
+        assert_eq!(list.nth_tail(10).head(), None);
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn prepend_all() {
+        let base = SinglyLinkedList::new().prepend(0);
+        let list = base.prepend_all(vec![1, 2, 3]);
+        assert_eq!(list, base.prepend(1).prepend(2).prepend(3));
+        assert_eq!(format!("{}", list), "3 -> 2 -> 1 -> 0");
+
+        // The old list is shared as the tail of the new one
+        assert!(std::ptr::eq(list.nth_tail(3).head().unwrap(), base.head().unwrap()));
+
+        let unchanged = base.prepend_all(std::iter::empty());
+        assert_eq!(unchanged, base);
+    }
+
+    #[test]
+    fn into_iter() {
+        let list: SinglyLinkedList<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let shared = list.tail();
+
+        let elements: Vec<String> = list.into_iter().collect();
+        assert_eq!(elements, vec!["a", "b", "c"]);
+
+        // Shared nodes were cloned out of, not moved out of
+        assert_eq!(shared.head().map(String::as_str), Some("b"));
+        assert_eq!(shared.len(), 2);
+    }
+
+    #[test]
+    fn last() {
+        let list = SinglyLinkedList::new().prepend(1).prepend(2).prepend(3);
+        assert_eq!(list.last(), Some(&1));
+        assert!(std::ptr::eq(list.last().unwrap(), list.tail().last().unwrap()));
+
+        assert_eq!(SinglyLinkedList::<i32>::new().last(), None);
+    }
+
+    #[test]
+    fn iter_rev() {
+        let list = SinglyLinkedList::new().prepend(1).prepend(2).prepend(3);
+
+        let mut iter = list.iter_rev();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(SinglyLinkedList::<i32>::new().iter_rev().next(), None);
+    }
+
+    #[test]
+    fn is_empty() {
+        let list = SinglyLinkedList::new();
+        assert!(list.is_empty());
+        let list = list.prepend(1);
+        assert!(!list.is_empty());
+        assert!(list.tail().is_empty());
+    }
+
+    #[test]
+    fn shares_tail_with() {
+        let list = SinglyLinkedList::new().prepend(1).prepend(2).prepend(3);
+        let tail = list.tail();
+        let other = tail.prepend(4);
+
+        assert!(tail.shares_tail_with(&other.tail()));
+        assert!(list.tail().shares_tail_with(&other.tail()));
+        assert!(!list.shares_tail_with(&other));
+
+        // Equal elements are not the same nodes
+        let copy = SinglyLinkedList::new().prepend(1).prepend(2);
+        assert!(tail == copy);
+        assert!(!tail.shares_tail_with(&copy));
+
+        assert!(!SinglyLinkedList::<i32>::new().shares_tail_with(&SinglyLinkedList::new()));
+    }
+
+    #[test]
+    fn head_strong_count() {
+        assert_eq!(SinglyLinkedList::<i32>::new().head_strong_count(), 0);
+
+        let list = SinglyLinkedList::new().prepend(1).prepend(2);
+        assert_eq!(list.head_strong_count(), 1);
+
+        let cloned = list.clone();
+        assert_eq!(list.head_strong_count(), 2);
+        assert_eq!(cloned.head_strong_count(), 2);
+
+        // The new node points at the old head, which adds another reference to it
+        let longer = list.prepend(3);
+        assert_eq!(list.head_strong_count(), 3);
+        assert_eq!(longer.head_strong_count(), 1);
+        assert_eq!(longer.tail().head_strong_count(), 4);
+
+        drop(cloned);
+        drop(longer);
+        assert_eq!(list.head_strong_count(), 1);
+    }
+
+    #[test]
+    fn fused() {
+        let list = SinglyLinkedList::new().prepend(1);
+        let mut iter = list.iterator().fuse();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = list.into_iter().fuse();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn map() {
+        let list = SinglyLinkedList::new().prepend(1).prepend(2).prepend(3);
+        let mapped = list.map(|element| element.to_string());
+        assert_eq!(mapped.head(), Some(&"3".to_string()));
+        assert_eq!(format!("{}", mapped), "3 -> 2 -> 1");
+        assert_eq!(mapped.head_strong_count(), 1);
+
+        // The original list is untouched
+        assert_eq!(list.head(), Some(&3));
+        assert!(SinglyLinkedList::<i32>::new().map(|element| element * 2).is_empty());
+    }
 }